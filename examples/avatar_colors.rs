@@ -0,0 +1,14 @@
+use random_color::showcase;
+
+fn main() {
+    for name in ["ada", "grace", "linus", "margaret"] {
+        let (background, text) = showcase::avatar_colors(name);
+
+        println!(
+            "{:<10} background {} text {}",
+            name,
+            background.to_hex(),
+            text.to_hex()
+        );
+    }
+}
@@ -0,0 +1,5 @@
+use random_color::showcase;
+
+fn main() {
+    print!("{}", showcase::palette_css_variables("brand", 5, "brand"));
+}
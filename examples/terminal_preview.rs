@@ -0,0 +1,5 @@
+use random_color::showcase;
+
+fn main() {
+    print!("{}", showcase::terminal_palette(42, 8, 64));
+}
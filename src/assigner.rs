@@ -0,0 +1,316 @@
+use crate::color::{Color, HexParseError};
+use crate::options::Gamut;
+use crate::RandomColor;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// Assigns a stable, distinct color to each key, e.g. tile IDs of a tilemap.
+///
+/// The first time a key is seen it receives a new color that differs from
+/// every color assigned so far by at least the configured CIEDE2000
+/// distance. Later lookups of the same key return the same color, without
+/// consuming the generator's random state.
+///
+/// With a capacity set, the least recently used generated colors are evicted
+/// once the capacity is exceeded, so memory stays bounded when coloring an
+/// unbounded stream of keys, such as log identifiers.
+#[derive(Debug, Clone)]
+pub struct ColorAssigner<K> {
+    /// The generator used to pick new colors.
+    pub generator: RandomColor,
+    /// The minimum CIEDE2000 difference between assigned colors.
+    pub min_delta_e: f32,
+    colors: HashMap<K, Color>,
+    order: BTreeMap<u64, K>,
+    positions: HashMap<K, u64>,
+    reserved: HashSet<K>,
+    capacity: Option<usize>,
+    clock: u64,
+    last_used: HashMap<K, u64>,
+    recency: BTreeMap<u64, K>,
+}
+
+impl<K: Hash + Eq + Clone> ColorAssigner<K> {
+    /// Creates a new `ColorAssigner` instance.
+    ///
+    /// Parameters:
+    /// * `generator`: The generator used to pick new colors.
+    /// * `min_delta_e`: The minimum CIEDE2000 difference between assigned
+    ///   colors.
+    pub fn new(generator: RandomColor, min_delta_e: f32) -> ColorAssigner<K> {
+        ColorAssigner {
+            generator,
+            min_delta_e,
+            colors: HashMap::new(),
+            order: BTreeMap::new(),
+            positions: HashMap::new(),
+            reserved: HashSet::new(),
+            capacity: None,
+            clock: 0,
+            last_used: HashMap::new(),
+            recency: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a new `ColorAssigner` instance with some keys pinned to fixed
+    /// colors, e.g. `0` to red for errors. Every other key receives a
+    /// generated color that stays away from the reserved ones.
+    ///
+    /// Parameters:
+    /// * `generator`: The generator used to pick new colors.
+    /// * `min_delta_e`: The minimum CIEDE2000 difference between assigned
+    ///   colors.
+    /// * `reserved`: The keys and their fixed colors.
+    pub fn with_reserved(
+        generator: RandomColor,
+        min_delta_e: f32,
+        reserved: &[(K, Color)],
+    ) -> ColorAssigner<K> {
+        let mut assigner = ColorAssigner::new(generator, min_delta_e);
+
+        for (key, color) in reserved {
+            assigner.reserve(key.clone(), *color);
+        }
+
+        assigner
+    }
+
+    /// Creates a new `ColorAssigner` instance for calendar events or tags,
+    /// picking new colors from the given gamuts.
+    ///
+    /// The existing events keep their colors, and every new event receives a
+    /// color distinct from all of them, re-rolling on collisions. Once
+    /// assigned, a color stays the same, and can be persisted with
+    /// `to_mapping_string`.
+    ///
+    /// Parameters:
+    /// * `generator`: The generator used to pick new colors.
+    /// * `gamuts`: The gamuts new colors are picked from, evenly. If empty,
+    ///   the hue settings of the generator are kept.
+    /// * `min_delta_e`: The minimum CIEDE2000 difference between colors.
+    /// * `existing`: The existing events and their colors.
+    pub fn for_calendar(
+        mut generator: RandomColor,
+        gamuts: &[Gamut],
+        min_delta_e: f32,
+        existing: &[(K, Color)],
+    ) -> ColorAssigner<K> {
+        if !gamuts.is_empty() {
+            let weights: Vec<(Gamut, u32)> = gamuts.iter().map(|gamut| (*gamut, 1)).collect();
+            generator.gamut_weights(&weights);
+        }
+
+        ColorAssigner::with_reserved(generator, min_delta_e, existing)
+    }
+
+    /// Pins a key to a fixed color, replacing any color it already had.
+    ///
+    /// Parameters:
+    /// * `key`: The key to pin.
+    /// * `color`: The fixed color of the key.
+    pub fn reserve(&mut self, key: K, color: Color) -> &mut ColorAssigner<K> {
+        if let Some(tick) = self.last_used.remove(&key) {
+            self.recency.remove(&tick);
+        }
+
+        self.reserved.insert(key.clone());
+        self.insert(key, color);
+
+        self
+    }
+
+    /// Limits the number of generated colors kept, evicting the least
+    /// recently used ones beyond it. Reserved keys are never evicted.
+    ///
+    /// An evicted key receives a new color the next time it is looked up, so
+    /// the capacity should exceed the number of keys in use at once.
+    ///
+    /// Parameters:
+    /// * `capacity`: The maximum number of generated colors kept.
+    pub fn capacity(&mut self, capacity: usize) -> &mut ColorAssigner<K> {
+        self.capacity = Some(capacity);
+        self.evict();
+
+        self
+    }
+
+    /// Returns the color of the given key, assigning a new one if the key has
+    /// not been seen before.
+    ///
+    /// Parameters:
+    /// * `key`: The key to get the color for.
+    pub fn get(&mut self, key: &K) -> Color {
+        if let Some(color) = self.colors.get(key).copied() {
+            self.touch(key);
+
+            return color;
+        }
+
+        let existing: Vec<Color> = self.order.values().map(|k| self.colors[k]).collect();
+        let color = self
+            .generator
+            .to_distinct_color(&existing, self.min_delta_e);
+
+        self.insert(key.clone(), color);
+        self.touch(key);
+        self.evict();
+
+        color
+    }
+
+    /// Returns the colors of the given keys, assigning new ones as needed.
+    ///
+    /// Parameters:
+    /// * `keys`: The keys to get the colors for, e.g. the tile IDs of a map.
+    pub fn colorize(&mut self, keys: &[K]) -> Vec<Color> {
+        keys.iter().map(|key| self.get(key)).collect()
+    }
+
+    /// Returns the color already assigned to the given key, if any.
+    ///
+    /// Parameters:
+    /// * `key`: The key to get the color for.
+    pub fn assigned(&self, key: &K) -> Option<Color> {
+        self.colors.get(key).copied()
+    }
+
+    /// Returns the number of assigned keys.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Checks whether no key has been assigned yet.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Marks a key as the most recently used one, if a capacity is set.
+    fn touch(&mut self, key: &K) {
+        if self.capacity.is_none() || self.reserved.contains(key) {
+            return;
+        }
+
+        self.clock += 1;
+
+        if let Some(tick) = self.last_used.insert(key.clone(), self.clock) {
+            self.recency.remove(&tick);
+        }
+
+        self.recency.insert(self.clock, key.clone());
+    }
+
+    /// Evicts the least recently used keys until the capacity is respected.
+    fn evict(&mut self) {
+        let capacity = match self.capacity {
+            Some(capacity) => capacity,
+            None => return,
+        };
+
+        while self.recency.len() > capacity {
+            let (_, key) = self.recency.pop_first().unwrap();
+
+            self.last_used.remove(&key);
+            self.colors.remove(&key);
+
+            if let Some(position) = self.positions.remove(&key) {
+                self.order.remove(&position);
+            }
+        }
+    }
+
+    /// Records the color of a key, appending it to the assignment order if
+    /// it is new.
+    fn insert(&mut self, key: K, color: Color) {
+        if self.colors.insert(key.clone(), color).is_none() {
+            let position = self.order.last_key_value().map_or(0, |(p, _)| p + 1);
+
+            self.positions.insert(key.clone(), position);
+            self.order.insert(position, key);
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone + fmt::Display> ColorAssigner<K> {
+    /// Serializes the assigned colors, one `key=#rrggbbaa` line per key in
+    /// assignment order.
+    pub fn to_mapping_string(&self) -> String {
+        self.order
+            .values()
+            .map(|key| {
+                let [r, g, b, a] = self.colors[key].to_rgba_array();
+                format!("{}=#{:02x}{:02x}{:02x}{:02x}\n", key, r, g, b, a)
+            })
+            .collect()
+    }
+}
+
+impl<K: Hash + Eq + Clone + FromStr> ColorAssigner<K> {
+    /// Restores an assigner from a mapping produced by `to_mapping_string`.
+    ///
+    /// Keys not present in the mapping will receive new colors distinct from
+    /// the restored ones.
+    ///
+    /// Parameters:
+    /// * `generator`: The generator used to pick new colors.
+    /// * `min_delta_e`: The minimum CIEDE2000 difference between assigned
+    ///   colors.
+    /// * `mapping`: The serialized mapping.
+    pub fn from_mapping_string(
+        generator: RandomColor,
+        min_delta_e: f32,
+        mapping: &str,
+    ) -> Result<ColorAssigner<K>, MappingParseError> {
+        let mut assigner = ColorAssigner::new(generator, min_delta_e);
+
+        for (i, line) in mapping.lines().enumerate() {
+            let line = line.trim();
+
+            if line.is_empty() {
+                continue;
+            }
+
+            let (key, color) = line
+                .rsplit_once('=')
+                .ok_or(MappingParseError::MissingSeparator(i + 1))?;
+            let key = key
+                .parse::<K>()
+                .map_err(|_| MappingParseError::InvalidKey(i + 1))?;
+            let color =
+                Color::from_hex(color).map_err(|e| MappingParseError::InvalidColor(i + 1, e))?;
+
+            assigner.insert(key, color);
+        }
+
+        Ok(assigner)
+    }
+}
+
+/// An error returned when restoring a `ColorAssigner` from a mapping fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MappingParseError {
+    /// The line with the given number has no `=` separator.
+    MissingSeparator(usize),
+    /// The key on the line with the given number could not be parsed.
+    InvalidKey(usize),
+    /// The color on the line with the given number could not be parsed.
+    InvalidColor(usize, HexParseError),
+}
+
+impl fmt::Display for MappingParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MappingParseError::MissingSeparator(line) => {
+                write!(f, "missing '=' separator on line {}", line)
+            }
+            MappingParseError::InvalidKey(line) => write!(f, "invalid key on line {}", line),
+            MappingParseError::InvalidColor(line, e) => {
+                write!(f, "invalid color on line {}: {}", line, e)
+            }
+        }
+    }
+}
+
+impl Error for MappingParseError {}
@@ -0,0 +1,39 @@
+/// A report of the error introduced by the lossy output paths for a single
+/// generated color, as returned by `RandomColor::audit_conversions`.
+///
+/// Errors are measured against the unquantized conversion of the same HSV
+/// color, and are useful to pick the most faithful output for a pipeline.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConversionAudit {
+    /// The generated color, as an HSV array.
+    pub hsv: [u32; 3],
+    /// The color as returned by the RGB and hex outputs.
+    pub rgb: [u8; 3],
+    /// The largest error of a single RGB channel, in the range [0.0, 255.0].
+    ///
+    /// The hex output shares the RGB path, so it has the same error.
+    pub rgb_error: f32,
+    /// The CIEDE2000 difference between the exact and the quantized RGB color.
+    pub rgb_delta_e: f32,
+    /// The largest error of a single HSV component after converting the RGB
+    /// output back to HSV.
+    pub hsv_round_trip_error: u32,
+    /// The color as returned by the HSL outputs.
+    pub hsl: [u32; 3],
+    /// The largest error of a single HSL component, in degrees for the hue
+    /// and percentage points for the saturation and lightness.
+    pub hsl_error: f32,
+}
+
+impl ConversionAudit {
+    /// Checks whether every output path is lossless up to `tolerance`, in
+    /// the units of each path's error.
+    ///
+    /// Parameters:
+    /// * `tolerance`: The maximum accepted error.
+    pub fn is_within(&self, tolerance: f32) -> bool {
+        self.rgb_error <= tolerance
+            && self.hsv_round_trip_error as f32 <= tolerance
+            && self.hsl_error <= tolerance
+    }
+}
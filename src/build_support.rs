@@ -0,0 +1,27 @@
+//! Helpers for baking generated colors into binaries from a build script.
+//!
+//! ```rust
+//! // build.rs
+//! let source = random_color::build_support::generate_static_palette("brand", 5);
+//! # let _ = source;
+//! // std::fs::write(out_dir.join("palette.rs"), source).unwrap();
+//! ```
+
+use crate::options::Seed;
+use crate::RandomColor;
+
+/// Generates a palette from a seed and returns it as Rust source declaring a
+/// `pub const PALETTE: [[u8; 3]; N]` of RGB colors.
+///
+/// The same seed always yields the same source, so the palette can be
+/// regenerated at build time without any runtime dependency.
+///
+/// Parameters:
+/// * `seed`: The seed of the palette.
+/// * `count`: The number of colors.
+pub fn generate_static_palette<T: Seed>(seed: T, count: usize) -> String {
+    RandomColor::new()
+        .seed(seed)
+        .to_palette(count)
+        .to_rust_source("PALETTE")
+}
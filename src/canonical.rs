@@ -0,0 +1,605 @@
+use crate::color::Color;
+use crate::color_dictionary::{ColorDictionary, ColorInformation};
+use crate::options::{
+    AlgorithmVersion, CssFormat, Curve, Fallback, Gamut, GamutMapping, GamutRotation, Luminosity,
+    LuminosityBlend, Near, RetryPolicy, Sampling, Style, ValueRange,
+};
+use crate::RandomColor;
+use std::error::Error;
+use std::fmt;
+
+/// The gamuts of a color dictionary, in the order their fields are stored.
+const DICTIONARY_GAMUTS: [Gamut; 8] = [
+    Gamut::Monochrome,
+    Gamut::Red,
+    Gamut::Orange,
+    Gamut::Yellow,
+    Gamut::Green,
+    Gamut::Blue,
+    Gamut::Purple,
+    Gamut::Pink,
+];
+
+/// The generation settings and HSV values stored in a canonical string.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct CanonicalRecord {
+    pub(crate) seed: u64,
+    pub(crate) generator: RandomColor,
+    pub(crate) hsv: [i64; 3],
+}
+
+impl CanonicalRecord {
+    /// Checks whether every setting of the generator can be stored in a
+    /// canonical string, that is, it has no candidate filter, pick strategy
+    /// or mix.
+    pub(crate) fn is_serializable(generator: &RandomColor) -> bool {
+        generator.candidate_filter.is_none()
+            && generator.pick_strategy.is_none()
+            && generator.mix.is_none()
+    }
+
+    /// Parses a record from its canonical string.
+    pub(crate) fn parse(canonical: &str) -> Result<CanonicalRecord, CanonicalParseError> {
+        let fields = canonical
+            .trim()
+            .strip_prefix("hsv:")
+            .ok_or(CanonicalParseError::InvalidPrefix)?;
+
+        let mut generator = RandomColor::new();
+        let mut seed = None;
+        let mut alpha = None;
+        let mut algorithm_version = None;
+        let mut hsv = [None; 3];
+
+        for field in fields.split(';') {
+            let (key, value) = field
+                .split_once('=')
+                .ok_or_else(|| CanonicalParseError::InvalidField(field.to_string()))?;
+            let invalid = || CanonicalParseError::InvalidField(key.to_string());
+
+            match key {
+                "seed" => seed = Some(value.parse().map_err(|_| invalid())?),
+                "hue" => generator.hue = Some(parse_gamut(value).ok_or_else(invalid)?),
+                "exact-hue" => generator.exact_hue = Some(value.parse().map_err(|_| invalid())?),
+                "luminosity" => {
+                    generator.luminosity = Some(parse_luminosity(value).ok_or_else(invalid)?)
+                }
+                "blend" => {
+                    generator.luminosity_blend = Some(parse_blend(value).ok_or_else(invalid)?)
+                }
+                "style" => generator.style = Some(parse_style(value).ok_or_else(invalid)?),
+                "mask" => generator.hue_mask = Some(parse_mask(value).ok_or_else(invalid)?),
+                "saturation" => {
+                    generator.saturation = Some(parse_range(value).ok_or_else(invalid)?)
+                }
+                "brightness" => {
+                    generator.brightness = Some(parse_range(value).ok_or_else(invalid)?)
+                }
+                "saturation-bias" => {
+                    generator.saturation_bias = Some(parse_curve(value).ok_or_else(invalid)?)
+                }
+                "value-bias" => {
+                    generator.value_bias = Some(parse_curve(value).ok_or_else(invalid)?)
+                }
+                "weights" => {
+                    generator.gamut_weights = Some(parse_weights(value).ok_or_else(invalid)?)
+                }
+                "rotation" => {
+                    generator.gamut_rotation = Some(parse_rotation(value).ok_or_else(invalid)?)
+                }
+                "near" => generator.near = Some(parse_near(value).ok_or_else(invalid)?),
+                "gap" => generator.min_lightness_gap = Some(value.parse().map_err(|_| invalid())?),
+                "jitter" => {
+                    let [r, g, b]: [u8; 3] = parse_list(value)
+                        .and_then(|list| list.try_into().ok())
+                        .ok_or_else(invalid)?;
+                    generator.channel_jitter = Some([r, g, b]);
+                }
+                "grayscale" => generator.grayscale = value.parse().map_err(|_| invalid())?,
+                "retry" => generator.retry_policy = parse_retry(value).ok_or_else(invalid)?,
+                "sampling" => {
+                    generator.sampling = match value {
+                        "interior" => Sampling::Interior,
+                        "edge" => Sampling::Edge,
+                        _ => return Err(invalid()),
+                    }
+                }
+                "format" => generator.default_format = parse_format(value).ok_or_else(invalid)?,
+                "mapping" => {
+                    generator.gamut_mapping = match value {
+                        "clip" => GamutMapping::Clip,
+                        "reduce-chroma" => GamutMapping::ReduceChroma,
+                        "css4" => GamutMapping::Css4,
+                        _ => return Err(invalid()),
+                    }
+                }
+                "a" => {
+                    alpha = match value {
+                        "random" => Some(None),
+                        value => Some(Some(value.parse().map_err(|_| invalid())?)),
+                    }
+                }
+                "version" => {
+                    algorithm_version = match value {
+                        "v1" => Some(AlgorithmVersion::V1),
+                        "v2" => Some(AlgorithmVersion::V2),
+                        _ => return Err(invalid()),
+                    }
+                }
+                "h" => hsv[0] = Some(value.parse().map_err(|_| invalid())?),
+                "s" => hsv[1] = Some(value.parse().map_err(|_| invalid())?),
+                "v" => hsv[2] = Some(value.parse().map_err(|_| invalid())?),
+                key => {
+                    let gamut = key
+                        .strip_prefix("dictionary-")
+                        .and_then(parse_gamut)
+                        .ok_or_else(invalid)?;
+                    *dictionary_entry(&mut generator.color_dictionary, gamut) =
+                        parse_information(value).ok_or_else(invalid)?;
+                }
+            }
+        }
+
+        generator.alpha = alpha.ok_or(CanonicalParseError::MissingField("a"))?;
+        generator.algorithm_version =
+            algorithm_version.ok_or(CanonicalParseError::MissingField("version"))?;
+
+        Ok(CanonicalRecord {
+            seed: seed.ok_or(CanonicalParseError::MissingField("seed"))?,
+            generator,
+            hsv: [
+                hsv[0].ok_or(CanonicalParseError::MissingField("h"))?,
+                hsv[1].ok_or(CanonicalParseError::MissingField("s"))?,
+                hsv[2].ok_or(CanonicalParseError::MissingField("v"))?,
+            ],
+        })
+    }
+}
+
+impl fmt::Display for CanonicalRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let generator = &self.generator;
+        let defaults = RandomColor::new();
+
+        write!(f, "hsv:seed={}", self.seed)?;
+
+        if let Some(hue) = generator.hue {
+            write!(f, ";hue={}", gamut_name(hue))?;
+        }
+
+        if let Some(hue) = generator.exact_hue {
+            write!(f, ";exact-hue={}", hue)?;
+        }
+
+        if let Some(luminosity) = generator.luminosity {
+            write!(f, ";luminosity={}", luminosity_name(luminosity))?;
+        }
+
+        if let Some(blend) = generator.luminosity_blend {
+            write!(
+                f,
+                ";blend={},{},{}",
+                luminosity_name(blend.from),
+                luminosity_name(blend.to),
+                blend.t
+            )?;
+        }
+
+        if let Some(style) = generator.style {
+            write!(f, ";style={}", style_name(style))?;
+        }
+
+        if let Some(mask) = &generator.hue_mask {
+            let digits: String = mask
+                .chunks(4)
+                .map(|chunk| {
+                    let nibble = chunk
+                        .iter()
+                        .enumerate()
+                        .fold(0, |nibble, (i, allowed)| nibble | (*allowed as u32) << i);
+
+                    char::from_digit(nibble, 16).unwrap()
+                })
+                .collect();
+
+            write!(f, ";mask={}", digits)?;
+        }
+
+        if let Some(range) = generator.saturation {
+            write!(f, ";saturation={}-{}", range.min, range.max)?;
+        }
+
+        if let Some(range) = generator.brightness {
+            write!(f, ";brightness={}-{}", range.min, range.max)?;
+        }
+
+        if let Some(curve) = generator.saturation_bias {
+            write!(f, ";saturation-bias={}", curve_name(curve))?;
+        }
+
+        if let Some(curve) = generator.value_bias {
+            write!(f, ";value-bias={}", curve_name(curve))?;
+        }
+
+        if let Some(weights) = &generator.gamut_weights {
+            let weights: Vec<String> = weights
+                .iter()
+                .map(|(gamut, weight)| format!("{}:{}", gamut_name(*gamut), weight))
+                .collect();
+
+            write!(f, ";weights={}", weights.join(","))?;
+        }
+
+        if let Some(rotation) = &generator.gamut_rotation {
+            let gamuts: Vec<&str> = rotation.gamuts.iter().map(|g| gamut_name(*g)).collect();
+
+            write!(f, ";rotation={}@{}", gamuts.join(","), rotation.next)?;
+        }
+
+        if let Some(near) = generator.near {
+            write!(
+                f,
+                ";near={},{},{},{}",
+                near.hue, near.saturation, near.brightness, near.tolerance
+            )?;
+        }
+
+        if let Some(gap) = generator.min_lightness_gap {
+            write!(f, ";gap={}", gap)?;
+        }
+
+        if let Some([r, g, b]) = generator.channel_jitter {
+            write!(f, ";jitter={},{},{}", r, g, b)?;
+        }
+
+        if generator.grayscale {
+            write!(f, ";grayscale=true")?;
+        }
+
+        if generator.retry_policy != defaults.retry_policy {
+            let fallback = match generator.retry_policy.on_exhausted {
+                Fallback::BestCandidate => "best".to_string(),
+                Fallback::LastCandidate => "last".to_string(),
+                Fallback::Color(c) => format!("#{:02x}{:02x}{:02x}{:02x}", c.r, c.g, c.b, c.a),
+            };
+
+            write!(
+                f,
+                ";retry={},{}",
+                generator.retry_policy.max_attempts, fallback
+            )?;
+        }
+
+        if generator.sampling == Sampling::Edge {
+            write!(f, ";sampling=edge")?;
+        }
+
+        if generator.default_format != defaults.default_format {
+            write!(f, ";format={}", format_name(generator.default_format))?;
+        }
+
+        let mapping = match generator.gamut_mapping {
+            GamutMapping::Clip => None,
+            GamutMapping::ReduceChroma => Some("reduce-chroma"),
+            GamutMapping::Css4 => Some("css4"),
+        };
+
+        if let Some(mapping) = mapping {
+            write!(f, ";mapping={}", mapping)?;
+        }
+
+        for gamut in DICTIONARY_GAMUTS {
+            let information = generator.color_dictionary.get_color_from_gamut(&gamut);
+
+            if information != defaults.color_dictionary.get_color_from_gamut(&gamut) {
+                write!(
+                    f,
+                    ";dictionary-{}={}",
+                    gamut_name(gamut),
+                    information_value(information)
+                )?;
+            }
+        }
+
+        match generator.alpha {
+            Some(alpha) => write!(f, ";a={}", alpha)?,
+            None => write!(f, ";a=random")?,
+        }
+
+        let version = match generator.algorithm_version {
+            AlgorithmVersion::V1 => "v1",
+            AlgorithmVersion::V2 => "v2",
+        };
+        let [h, s, v] = self.hsv;
+
+        write!(f, ";version={};h={};s={};v={}", version, h, s, v)
+    }
+}
+
+fn gamut_name(gamut: Gamut) -> &'static str {
+    match gamut {
+        Gamut::Monochrome => "monochrome",
+        Gamut::Red => "red",
+        Gamut::Orange => "orange",
+        Gamut::Yellow => "yellow",
+        Gamut::Green => "green",
+        Gamut::Blue => "blue",
+        Gamut::Purple => "purple",
+        Gamut::Pink => "pink",
+    }
+}
+
+fn parse_gamut(name: &str) -> Option<Gamut> {
+    match name {
+        "monochrome" => Some(Gamut::Monochrome),
+        "red" => Some(Gamut::Red),
+        "orange" => Some(Gamut::Orange),
+        "yellow" => Some(Gamut::Yellow),
+        "green" => Some(Gamut::Green),
+        "blue" => Some(Gamut::Blue),
+        "purple" => Some(Gamut::Purple),
+        "pink" => Some(Gamut::Pink),
+        _ => None,
+    }
+}
+
+fn luminosity_name(luminosity: Luminosity) -> &'static str {
+    match luminosity {
+        Luminosity::Random => "random",
+        Luminosity::Bright => "bright",
+        Luminosity::Light => "light",
+        Luminosity::Dark => "dark",
+    }
+}
+
+fn parse_luminosity(name: &str) -> Option<Luminosity> {
+    match name {
+        "random" => Some(Luminosity::Random),
+        "bright" => Some(Luminosity::Bright),
+        "light" => Some(Luminosity::Light),
+        "dark" => Some(Luminosity::Dark),
+        _ => None,
+    }
+}
+
+fn style_name(style: Style) -> &'static str {
+    match style {
+        Style::Pastel => "pastel",
+        Style::Neon => "neon",
+        Style::Muted => "muted",
+        Style::Vivid => "vivid",
+    }
+}
+
+fn parse_style(name: &str) -> Option<Style> {
+    match name {
+        "pastel" => Some(Style::Pastel),
+        "neon" => Some(Style::Neon),
+        "muted" => Some(Style::Muted),
+        "vivid" => Some(Style::Vivid),
+        _ => None,
+    }
+}
+
+fn curve_name(curve: Curve) -> String {
+    match curve {
+        Curve::Linear => "linear".to_string(),
+        Curve::EaseIn => "ease-in".to_string(),
+        Curve::EaseOut => "ease-out".to_string(),
+        Curve::EaseInOut => "ease-in-out".to_string(),
+        Curve::Power(exponent) => format!("power:{}", exponent),
+    }
+}
+
+fn parse_curve(name: &str) -> Option<Curve> {
+    match name {
+        "linear" => Some(Curve::Linear),
+        "ease-in" => Some(Curve::EaseIn),
+        "ease-out" => Some(Curve::EaseOut),
+        "ease-in-out" => Some(Curve::EaseInOut),
+        name => Some(Curve::Power(name.strip_prefix("power:")?.parse().ok()?)),
+    }
+}
+
+fn format_name(format: CssFormat) -> &'static str {
+    match format {
+        CssFormat::Hex => "hex",
+        CssFormat::HexWithAlpha => "hex-alpha",
+        CssFormat::Rgb => "rgb",
+        CssFormat::Rgba => "rgba",
+        CssFormat::ModernRgb => "modern-rgb",
+        CssFormat::Hsl => "hsl",
+        CssFormat::Hsla => "hsla",
+        CssFormat::ModernHsl => "modern-hsl",
+        CssFormat::Hwb => "hwb",
+        CssFormat::Lch => "lch",
+        CssFormat::Oklch => "oklch",
+        CssFormat::DisplayP3 => "display-p3",
+    }
+}
+
+fn parse_format(name: &str) -> Option<CssFormat> {
+    match name {
+        "hex" => Some(CssFormat::Hex),
+        "hex-alpha" => Some(CssFormat::HexWithAlpha),
+        "rgb" => Some(CssFormat::Rgb),
+        "rgba" => Some(CssFormat::Rgba),
+        "modern-rgb" => Some(CssFormat::ModernRgb),
+        "hsl" => Some(CssFormat::Hsl),
+        "hsla" => Some(CssFormat::Hsla),
+        "modern-hsl" => Some(CssFormat::ModernHsl),
+        "hwb" => Some(CssFormat::Hwb),
+        "lch" => Some(CssFormat::Lch),
+        "oklch" => Some(CssFormat::Oklch),
+        "display-p3" => Some(CssFormat::DisplayP3),
+        _ => None,
+    }
+}
+
+/// Parses a comma-separated list of numbers.
+fn parse_list<T: std::str::FromStr>(value: &str) -> Option<Vec<T>> {
+    value.split(',').map(|item| item.parse().ok()).collect()
+}
+
+fn parse_blend(value: &str) -> Option<LuminosityBlend> {
+    let mut parts = value.split(',');
+    let from = parse_luminosity(parts.next()?)?;
+    let to = parse_luminosity(parts.next()?)?;
+    let t = parts.next()?.parse().ok()?;
+
+    match parts.next() {
+        Some(_) => None,
+        None => Some(LuminosityBlend { from, to, t }),
+    }
+}
+
+fn parse_mask(value: &str) -> Option<Box<[bool; 360]>> {
+    if value.len() != 90 {
+        return None;
+    }
+
+    let mut mask = Box::new([false; 360]);
+
+    for (i, digit) in value.chars().enumerate() {
+        let nibble = digit.to_digit(16)?;
+
+        for bit in 0..4 {
+            mask[i * 4 + bit] = nibble >> bit & 1 == 1;
+        }
+    }
+
+    Some(mask)
+}
+
+fn parse_range(value: &str) -> Option<ValueRange> {
+    let (min, max) = value.split_once('-')?;
+
+    Some(ValueRange {
+        min: min.parse().ok()?,
+        max: max.parse().ok()?,
+    })
+}
+
+fn parse_weights(value: &str) -> Option<Vec<(Gamut, u32)>> {
+    value
+        .split(',')
+        .map(|item| {
+            let (gamut, weight) = item.split_once(':')?;
+
+            Some((parse_gamut(gamut)?, weight.parse().ok()?))
+        })
+        .collect()
+}
+
+fn parse_rotation(value: &str) -> Option<GamutRotation> {
+    let (gamuts, next) = value.split_once('@')?;
+
+    Some(GamutRotation {
+        gamuts: gamuts.split(',').map(parse_gamut).collect::<Option<_>>()?,
+        next: next.parse().ok()?,
+    })
+}
+
+fn parse_near(value: &str) -> Option<Near> {
+    let [hue, saturation, brightness, tolerance]: [i64; 4] = parse_list(value)?.try_into().ok()?;
+
+    Some(Near {
+        hue,
+        saturation,
+        brightness,
+        tolerance,
+    })
+}
+
+fn parse_retry(value: &str) -> Option<RetryPolicy> {
+    let (max_attempts, fallback) = value.split_once(',')?;
+    let on_exhausted = match fallback {
+        "best" => Fallback::BestCandidate,
+        "last" => Fallback::LastCandidate,
+        hex => Fallback::Color(Color::from_hex(hex).ok()?),
+    };
+
+    Some(RetryPolicy {
+        max_attempts: max_attempts.parse().ok()?,
+        on_exhausted,
+    })
+}
+
+/// Stores the color information of a gamut as its hue range, lower bounds,
+/// saturation range and value range, separated by slashes.
+fn information_value(information: &ColorInformation) -> String {
+    let join = |values: &[i64]| {
+        values
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+
+    format!(
+        "{}/{}/{}/{}",
+        join(&information.range),
+        join(information.lower_bounds.concat().as_slice()),
+        join(&information.saturation_range),
+        join(&information.value_range)
+    )
+}
+
+fn parse_information(value: &str) -> Option<ColorInformation> {
+    let parts: Vec<Vec<i64>> = value.split('/').map(parse_list).collect::<Option<_>>()?;
+    let [range, lower_bounds, saturation_range, value_range]: [Vec<i64>; 4] =
+        parts.try_into().ok()?;
+
+    if lower_bounds.len() % 2 != 0 {
+        return None;
+    }
+
+    Some(ColorInformation {
+        range: range.try_into().ok()?,
+        lower_bounds: lower_bounds.chunks(2).map(|b| [b[0], b[1]]).collect(),
+        saturation_range: saturation_range.try_into().ok()?,
+        value_range: value_range.try_into().ok()?,
+    })
+}
+
+fn dictionary_entry(dictionary: &mut ColorDictionary, gamut: Gamut) -> &mut ColorInformation {
+    match gamut {
+        Gamut::Monochrome => &mut dictionary.monochrome,
+        Gamut::Red => &mut dictionary.red,
+        Gamut::Orange => &mut dictionary.orange,
+        Gamut::Yellow => &mut dictionary.yellow,
+        Gamut::Green => &mut dictionary.green,
+        Gamut::Blue => &mut dictionary.blue,
+        Gamut::Purple => &mut dictionary.purple,
+        Gamut::Pink => &mut dictionary.pink,
+    }
+}
+
+/// An error returned when restoring a color from a canonical string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CanonicalParseError {
+    /// The string does not start with `hsv:`.
+    InvalidPrefix,
+    /// The field with the given name is missing.
+    MissingField(&'static str),
+    /// The field with the given name is unknown or has an invalid value.
+    InvalidField(String),
+    /// The stored color differs from the one regenerated from the stored
+    /// parameters, e.g. because it was produced by an incompatible version.
+    Mismatch,
+}
+
+impl fmt::Display for CanonicalParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CanonicalParseError::InvalidPrefix => write!(f, "missing 'hsv:' prefix"),
+            CanonicalParseError::MissingField(key) => write!(f, "missing field '{}'", key),
+            CanonicalParseError::InvalidField(key) => write!(f, "invalid field '{}'", key),
+            CanonicalParseError::Mismatch => {
+                write!(f, "stored color does not match the regenerated one")
+            }
+        }
+    }
+}
+
+impl Error for CanonicalParseError {}
@@ -0,0 +1,467 @@
+use crate::color_dictionary::DEFAULT_COLORS;
+use crate::conversions;
+use crate::difference;
+use crate::options::{Gamut, GamutMapping};
+use std::error::Error;
+use std::fmt;
+
+/// A concrete color value, as produced by `RandomColor::to_color`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    /// The red channel.
+    pub r: u8,
+    /// The green channel.
+    pub g: u8,
+    /// The blue channel.
+    pub b: u8,
+    /// The alpha channel.
+    pub a: u8,
+}
+
+impl Color {
+    /// Creates a new `Color` instance.
+    pub fn new(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color { r, g, b, a }
+    }
+
+    /// Parses a color from a hex string.
+    ///
+    /// The leading `#` is optional, and the `RGB`, `RGBA`, `RRGGBB` and
+    /// `RRGGBBAA` forms are accepted. Colors without an alpha channel are
+    /// fully opaque.
+    ///
+    /// Parameters:
+    /// * `hex`: The hex string to parse.
+    pub fn from_hex(hex: &str) -> Result<Color, HexParseError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+
+        if let Some(c) = digits.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(HexParseError::InvalidDigit(c));
+        }
+
+        let channels: Vec<u8> = match digits.len() {
+            3 | 4 => digits
+                .chars()
+                .map(|c| {
+                    let value = c.to_digit(16).unwrap() as u8;
+                    value << 4 | value
+                })
+                .collect(),
+            6 | 8 => (0..digits.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).unwrap())
+                .collect(),
+            len => return Err(HexParseError::InvalidLength(len)),
+        };
+
+        Ok(Color {
+            r: channels[0],
+            g: channels[1],
+            b: channels[2],
+            a: channels.get(3).copied().unwrap_or(255),
+        })
+    }
+
+    /// Returns the color as a hex string, without the alpha channel.
+    pub fn to_hex(&self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// Returns the color as an RGB array.
+    pub fn to_rgb_array(&self) -> [u8; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    /// Returns the color as an RGBA array.
+    pub fn to_rgba_array(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Returns the color as an HSV array, with the hue in the range [0, 360)
+    /// and the saturation and brightness in the range [0, 100].
+    pub fn to_hsv_array(&self) -> [u32; 3] {
+        let r = self.r as f32 / 255.0;
+        let g = self.g as f32 / 255.0;
+        let b = self.b as f32 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let hue = if delta == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        let saturation = if max == 0.0 { 0.0 } else { delta / max };
+
+        [
+            hue.round() as u32 % 360,
+            (saturation * 100.0).round() as u32,
+            (max * 100.0).round() as u32,
+        ]
+    }
+
+    /// Returns the color rendered as a block of terminal background color,
+    /// using 24-bit ANSI escape codes, followed by its hex string.
+    #[cfg(feature = "ansi_preview")]
+    pub fn preview_string(&self) -> String {
+        format!(
+            "\x1b[48;2;{};{};{}m      \x1b[0m {}",
+            self.r,
+            self.g,
+            self.b,
+            self.to_hex()
+        )
+    }
+
+    /// Returns the color as an HSL array, with the hue in the range [0, 360)
+    /// and the saturation and lightness in the range [0, 100].
+    pub fn to_hsl_array(&self) -> [u32; 3] {
+        let [r, g, b] = [self.r, self.g, self.b].map(|c| c as f32 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (max + min) / 2.0;
+
+        let saturation = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        [
+            self.to_hsv_array()[0],
+            (saturation * 100.0).round() as u32,
+            (lightness * 100.0).round() as u32,
+        ]
+    }
+
+    /// Returns the CIEDE2000 difference between this color and another one.
+    ///
+    /// Alpha is not taken into account. A difference below 1.0 is generally
+    /// not perceptible.
+    ///
+    /// Parameters:
+    /// * `other`: The color to compare against.
+    pub fn delta_e(&self, other: &Color) -> f32 {
+        difference::ciede2000(self.to_lab(), other.to_lab())
+    }
+
+    /// Returns the difference between this color and another one with the
+    /// given formula.
+    ///
+    /// CIE94 and CMC l:c are not symmetric: this color is the reference and
+    /// `other` is the sample. Alpha is not taken into account.
+    ///
+    /// Parameters:
+    /// * `other`: The color to compare against.
+    /// * `method`: The color difference formula.
+    pub fn delta_e_with(&self, other: &Color, method: DeltaEMethod) -> f32 {
+        let (reference, sample) = (self.to_lab(), other.to_lab());
+
+        match method {
+            DeltaEMethod::Cie76 => difference::cie76(reference, sample),
+            DeltaEMethod::Cie94GraphicArts => {
+                difference::cie94(reference, sample, 1.0, 0.045, 0.015)
+            }
+            DeltaEMethod::Cie94Textiles => difference::cie94(reference, sample, 2.0, 0.048, 0.014),
+            DeltaEMethod::Cmc { lightness, chroma } => {
+                difference::cmc(reference, sample, lightness, chroma)
+            }
+            DeltaEMethod::Ciede2000 => difference::ciede2000(reference, sample),
+        }
+    }
+
+    /// Mixes this color with another one, e.g. for blending generated
+    /// colors into a palette.
+    ///
+    /// Parameters:
+    /// * `other`: The color to mix with.
+    /// * `weight`: The proportion of `other`, clamped to the range
+    ///   [0.0, 1.0].
+    /// * `mode`: How the colors are combined.
+    pub fn mix(&self, other: &Color, weight: f32, mode: MixMode) -> Color {
+        self.mix_mapped(other, weight, mode, GamutMapping::default())
+    }
+
+    /// Mixes this color with another one like `mix`, bringing mixtures that
+    /// fall outside the sRGB gamut, e.g. in OKLab, into it with the given
+    /// gamut mapping instead of clipping them.
+    ///
+    /// Parameters:
+    /// * `other`: The color to mix with.
+    /// * `weight`: The proportion of `other`, clamped to the range
+    ///   [0.0, 1.0].
+    /// * `mode`: How the colors are combined.
+    /// * `mapping`: The gamut mapping.
+    pub fn mix_mapped(
+        &self,
+        other: &Color,
+        weight: f32,
+        mode: MixMode,
+        mapping: GamutMapping,
+    ) -> Color {
+        let t = weight.clamp(0.0, 1.0);
+        let (from, to) = (
+            [self.r, self.g, self.b].map(|c| c as f32 / 255.0),
+            [other.r, other.g, other.b].map(|c| c as f32 / 255.0),
+        );
+        let lerp = |a: [f32; 3], b: [f32; 3]| [0, 1, 2].map(|c| a[c] + (b[c] - a[c]) * t);
+
+        let rgb = match mode {
+            MixMode::Linear => lerp(
+                from.map(conversions::srgb_to_linear),
+                to.map(conversions::srgb_to_linear),
+            )
+            .map(conversions::linear_to_srgb),
+            MixMode::Oklab => conversions::oklab_to_rgb(lerp(
+                conversions::rgb_to_oklab(from),
+                conversions::rgb_to_oklab(to),
+            )),
+            #[cfg(feature = "pigment_mixing")]
+            MixMode::Pigment => conversions::pigment_mix(from, to, t),
+        };
+
+        let [r, g, b] = mapping.map(rgb);
+        let a = (self.a as f32 + (other.a as f32 - self.a as f32) * t).round() as u8;
+
+        Color::new(r, g, b, a)
+    }
+
+    /// Checks whether this color is perceptually equal to another one, that
+    /// is, their CIEDE2000 difference is at most `tolerance`.
+    ///
+    /// Parameters:
+    /// * `other`: The color to compare against.
+    /// * `tolerance`: The maximum CIEDE2000 difference.
+    pub fn approx_eq(&self, other: &Color, tolerance: f32) -> bool {
+        self.delta_e(other) <= tolerance
+    }
+
+    /// Returns the WCAG relative luminance of the color, in the range
+    /// [0.0, 1.0]. Alpha is not taken into account.
+    pub fn relative_luminance(&self) -> f32 {
+        let [r, g, b] =
+            [self.r, self.g, self.b].map(|c| conversions::srgb_to_linear(c as f32 / 255.0));
+
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Returns the WCAG contrast ratio between this color and another one,
+    /// in the range [1.0, 21.0]. Alpha is not taken into account.
+    ///
+    /// Parameters:
+    /// * `other`: The color to compare against.
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns black or white, whichever contrasts the most with the color,
+    /// for text drawn on it. The WCAG contrast ratio is always at least 4.5.
+    pub fn readable_text_color(&self) -> Color {
+        let black = Color::new(0, 0, 0, 255);
+        let white = Color::new(255, 255, 255, 255);
+
+        match self.contrast_ratio(&black) >= self.contrast_ratio(&white) {
+            true => black,
+            false => white,
+        }
+    }
+
+    /// Returns the dominant wavelength of the color in nanometers, relative
+    /// to the D65 white point, or `None` for grays.
+    ///
+    /// Purples have no dominant wavelength, so their complementary wavelength
+    /// is returned as a negative value instead, e.g. `-530.0`.
+    pub fn dominant_wavelength(&self) -> Option<f32> {
+        conversions::dominant_wavelength(self.to_xyz()).map(|(wavelength, _)| wavelength)
+    }
+
+    /// Returns the excitation purity of the color, in the range [0.0, 1.0],
+    /// that is, how close it is to the spectral color of its dominant
+    /// wavelength, or to the line of purples, rather than to the D65 white
+    /// point.
+    pub fn excitation_purity(&self) -> f32 {
+        conversions::dominant_wavelength(self.to_xyz()).map_or(0.0, |(_, purity)| purity)
+    }
+
+    /// Classifies the color as warm, cool or neutral.
+    ///
+    /// Reds, oranges, yellows and pinks are warm, while greens, blues and
+    /// purples are cool, with pinks starting where the Pink gamut of the
+    /// default dictionary does. Colors with a saturation or brightness below
+    /// 10 are neutral, regardless of their hue.
+    pub fn temperature(&self) -> Temperature {
+        let [h, s, v] = self.to_hsv_array();
+        let pink_start = DEFAULT_COLORS[Gamut::Pink as usize].0[0] as u32;
+
+        if s < 10 || v < 10 {
+            Temperature::Neutral
+        } else if (75..pink_start).contains(&h) {
+            Temperature::Cool
+        } else {
+            Temperature::Warm
+        }
+    }
+
+    /// Returns descriptive mood tags for the color, derived from its hue,
+    /// saturation and brightness.
+    ///
+    /// A color can have several tags, or none at all.
+    pub fn mood_tags(&self) -> Vec<Mood> {
+        let [h, s, v] = self.to_hsv_array();
+        let warm = self.temperature() == Temperature::Warm;
+        let mut tags = Vec::new();
+
+        if (90..270).contains(&h) && s <= 60 && v >= 50 {
+            tags.push(Mood::Calm);
+        }
+
+        if warm && s >= 70 && v >= 70 {
+            tags.push(Mood::Energetic);
+        }
+
+        if ((250..320).contains(&h) && s >= 40 && v <= 60) || (s >= 30 && v < 35) {
+            tags.push(Mood::Luxurious);
+        }
+
+        if (45..=85).contains(&s) && v >= 85 {
+            tags.push(Mood::Playful);
+        }
+
+        if (20..60).contains(&h) && (30..=70).contains(&s) && (25..=65).contains(&v) {
+            tags.push(Mood::Earthy);
+        }
+
+        if s < 20 && v < 50 {
+            tags.push(Mood::Serious);
+        }
+
+        tags
+    }
+
+    /// Returns the color as CIE XYZ, relative to the D65 white point.
+    pub(crate) fn to_xyz(self) -> [f32; 3] {
+        conversions::rgb_to_xyz([self.r, self.g, self.b].map(|c| c as f32 / 255.0))
+    }
+
+    /// Returns the color as CIE L*a*b*, relative to the D65 white point.
+    pub(crate) fn to_lab(self) -> [f32; 3] {
+        conversions::rgb_to_lab([self.r, self.g, self.b].map(|c| c as f32 / 255.0))
+    }
+}
+
+/// Formats the color as lowercase hex digits, without the alpha channel.
+///
+/// The alternate flag prepends a `#`, so `format!("{:#x}", color)` matches
+/// `Color::to_hex`.
+impl fmt::LowerHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "#")?;
+        }
+
+        write!(f, "{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Formats the color as uppercase hex digits, without the alpha channel.
+///
+/// The alternate flag prepends a `#`.
+impl fmt::UpperHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "#")?;
+        }
+
+        write!(f, "{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+}
+
+/// A color difference formula, as used by `Color::delta_e_with`.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum DeltaEMethod {
+    /// The Euclidean distance in CIE L*a*b*.
+    Cie76,
+    /// CIE94 with the graphic arts weights.
+    Cie94GraphicArts,
+    /// CIE94 with the textiles weights.
+    Cie94Textiles,
+    /// CMC l:c, with the given lightness and chroma weights, e.g. 2:1 for
+    /// acceptability and 1:1 for perceptibility.
+    Cmc { lightness: f32, chroma: f32 },
+    /// CIEDE2000, as used by `Color::delta_e`.
+    #[default]
+    Ciede2000,
+}
+
+/// How two colors are combined, as used by `Color::mix`.
+///
+/// The enum is non-exhaustive, as some modes are only available with
+/// optional features.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MixMode {
+    /// Additive mixing of light, interpolating the linear sRGB channels.
+    #[default]
+    Linear,
+    /// Interpolation in OKLab, keeping the perceived lightness even.
+    Oklab,
+    /// Subtractive mixing of paint, with a Kubelka–Munk model, so blue and
+    /// yellow make green.
+    #[cfg(feature = "pigment_mixing")]
+    Pigment,
+}
+
+/// The perceived temperature of a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Temperature {
+    Warm,
+    Cool,
+    Neutral,
+}
+
+/// A descriptive mood associated with a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Mood {
+    Calm,
+    Energetic,
+    Luxurious,
+    Playful,
+    Earthy,
+    Serious,
+}
+
+/// An error returned when parsing a color from a hex string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HexParseError {
+    /// The string does not have 3, 4, 6 or 8 hex digits.
+    InvalidLength(usize),
+    /// The string contains a character that is not a hex digit.
+    InvalidDigit(char),
+}
+
+impl fmt::Display for HexParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HexParseError::InvalidLength(len) => {
+                write!(
+                    f,
+                    "invalid hex color length {}, expected 3, 4, 6 or 8 digits",
+                    len
+                )
+            }
+            HexParseError::InvalidDigit(c) => write!(f, "invalid hex digit '{}'", c),
+        }
+    }
+}
+
+impl Error for HexParseError {}
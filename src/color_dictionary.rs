@@ -1,5 +1,103 @@
 use crate::options::Gamut;
 
+/// The hue range and lower bounds of each gamut of the default dictionary,
+/// in `Gamut` order.
+pub(crate) const DEFAULT_COLORS: [([i64; 2], &[[i64; 2]]); 8] = [
+    ([0, 0], &[[0, 0], [100, 0]]),
+    (
+        [-26, 18],
+        &[
+            [20, 100],
+            [30, 92],
+            [40, 89],
+            [50, 85],
+            [60, 78],
+            [70, 70],
+            [80, 60],
+            [90, 55],
+            [100, 50],
+        ],
+    ),
+    (
+        [19, 46],
+        &[
+            [20, 100],
+            [30, 93],
+            [40, 88],
+            [50, 86],
+            [60, 85],
+            [70, 70],
+            [100, 70],
+        ],
+    ),
+    (
+        [47, 62],
+        &[
+            [25, 100],
+            [40, 94],
+            [50, 89],
+            [60, 86],
+            [70, 84],
+            [80, 82],
+            [90, 80],
+            [100, 75],
+        ],
+    ),
+    (
+        [63, 178],
+        &[
+            [30, 100],
+            [40, 90],
+            [50, 85],
+            [60, 81],
+            [70, 74],
+            [80, 64],
+            [90, 50],
+            [100, 40],
+        ],
+    ),
+    (
+        [179, 257],
+        &[
+            [20, 100],
+            [30, 86],
+            [40, 80],
+            [50, 74],
+            [60, 60],
+            [70, 52],
+            [80, 44],
+            [90, 39],
+            [100, 35],
+        ],
+    ),
+    (
+        [258, 282],
+        &[
+            [20, 100],
+            [30, 87],
+            [40, 79],
+            [50, 70],
+            [60, 65],
+            [70, 59],
+            [80, 52],
+            [90, 45],
+            [100, 42],
+        ],
+    ),
+    (
+        [283, 334],
+        &[
+            [20, 100],
+            [30, 90],
+            [40, 86],
+            [60, 84],
+            [80, 80],
+            [90, 75],
+            [100, 73],
+        ],
+    ),
+];
+
 /// Color information for a given hue.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ColorInformation {
@@ -64,100 +162,18 @@ pub struct ColorDictionary {
 impl ColorDictionary {
     /// Creates a new `ColorDictionary` instance.
     pub fn new() -> ColorDictionary {
+        let [monochrome, red, orange, yellow, green, blue, purple, pink] = DEFAULT_COLORS
+            .map(|(range, lower_bounds)| ColorInformation::new(range, lower_bounds.to_vec()));
+
         ColorDictionary {
-            monochrome: ColorInformation::new([0, 0], vec![[0, 0], [100, 0]]),
-            red: ColorInformation::new(
-                [-26, 18],
-                vec![
-                    [20, 100],
-                    [30, 92],
-                    [40, 89],
-                    [50, 85],
-                    [60, 78],
-                    [70, 70],
-                    [80, 60],
-                    [90, 55],
-                    [100, 50],
-                ],
-            ),
-            orange: ColorInformation::new(
-                [19, 46],
-                vec![
-                    [20, 100],
-                    [30, 93],
-                    [40, 88],
-                    [50, 86],
-                    [60, 85],
-                    [70, 70],
-                    [100, 70],
-                ],
-            ),
-            yellow: ColorInformation::new(
-                [47, 62],
-                vec![
-                    [25, 100],
-                    [40, 94],
-                    [50, 89],
-                    [60, 86],
-                    [70, 84],
-                    [80, 82],
-                    [90, 80],
-                    [100, 75],
-                ],
-            ),
-            green: ColorInformation::new(
-                [63, 178],
-                vec![
-                    [30, 100],
-                    [40, 90],
-                    [50, 85],
-                    [60, 81],
-                    [70, 74],
-                    [80, 64],
-                    [90, 50],
-                    [100, 40],
-                ],
-            ),
-            blue: ColorInformation::new(
-                [179, 257],
-                vec![
-                    [20, 100],
-                    [30, 86],
-                    [40, 80],
-                    [50, 74],
-                    [60, 60],
-                    [70, 52],
-                    [80, 44],
-                    [90, 39],
-                    [100, 35],
-                ],
-            ),
-            purple: ColorInformation::new(
-                [258, 282],
-                vec![
-                    [20, 100],
-                    [30, 87],
-                    [40, 79],
-                    [50, 70],
-                    [60, 65],
-                    [70, 59],
-                    [80, 52],
-                    [90, 45],
-                    [100, 42],
-                ],
-            ),
-            pink: ColorInformation::new(
-                [283, 334],
-                vec![
-                    [20, 100],
-                    [30, 90],
-                    [40, 86],
-                    [60, 84],
-                    [80, 80],
-                    [90, 75],
-                    [100, 73],
-                ],
-            ),
+            monochrome,
+            red,
+            orange,
+            yellow,
+            green,
+            blue,
+            purple,
+            pink,
         }
     }
 
@@ -0,0 +1,304 @@
+use crate::color::Color;
+use crate::conversions;
+use crate::RandomColor;
+
+/// A fill pattern suggested alongside a color, so charts can encode
+/// categories with both color and texture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pattern {
+    Solid = 0,
+    Dots = 1,
+    Stripes = 2,
+    CrossHatch = 3,
+    Diagonal = 4,
+    Grid = 5,
+}
+
+impl Pattern {
+    /// All the patterns, in the order they are suggested.
+    pub const ALL: [Pattern; 6] = [
+        Pattern::Solid,
+        Pattern::Dots,
+        Pattern::Stripes,
+        Pattern::CrossHatch,
+        Pattern::Diagonal,
+        Pattern::Grid,
+    ];
+}
+
+/// A color that changed between two palettes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorShift {
+    /// The position of the color in the palettes.
+    pub index: usize,
+    /// The color in the older palette.
+    pub from: Color,
+    /// The color in the newer palette.
+    pub to: Color,
+    /// The CIEDE2000 difference between both colors.
+    pub delta_e: f32,
+}
+
+/// The differences between two palettes, as returned by `Palette::diff`.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct PaletteDiff {
+    /// The colors only present at the end of the newer palette.
+    pub added: Vec<Color>,
+    /// The colors only present at the end of the older palette.
+    pub removed: Vec<Color>,
+    /// The colors that changed in the slots both palettes share.
+    pub shifted: Vec<ColorShift>,
+}
+
+impl PaletteDiff {
+    /// Checks whether both palettes are identical.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.shifted.is_empty()
+    }
+
+    /// Returns the largest CIEDE2000 difference among the shifted colors, or
+    /// 0.0 if none shifted.
+    pub fn max_delta_e(&self) -> f32 {
+        self.shifted
+            .iter()
+            .map(|shift| shift.delta_e)
+            .fold(0.0, f32::max)
+    }
+}
+
+/// An ordered set of generated colors.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Palette {
+    /// The colors of the palette.
+    pub colors: Vec<Color>,
+}
+
+impl Palette {
+    /// Creates a new `Palette` instance.
+    pub fn new(colors: Vec<Color>) -> Palette {
+        Palette { colors }
+    }
+
+    /// Returns the number of colors in the palette.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Checks whether the palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+
+    /// Returns an iterator over the colors of the palette.
+    pub fn iter(&self) -> std::slice::Iter<'_, Color> {
+        self.colors.iter()
+    }
+
+    /// Re-rolls every color of the palette except the anchored ones, which
+    /// are kept in place, e.g. to keep the second and fifth colors while
+    /// iterating on the rest.
+    ///
+    /// Anchors outside the palette are ignored.
+    ///
+    /// Parameters:
+    /// * `generator`: The generator used to re-roll the colors.
+    /// * `anchors`: The positions of the colors to keep.
+    pub fn regenerate_keeping(
+        &mut self,
+        generator: &mut RandomColor,
+        anchors: &[usize],
+    ) -> &mut Palette {
+        for (i, color) in self.colors.iter_mut().enumerate() {
+            if !anchors.contains(&i) {
+                *color = generator.to_color();
+            }
+        }
+
+        self
+    }
+
+    /// Returns a suggested fill pattern for each color of the palette, as a
+    /// fallback for readers who cannot tell the colors apart.
+    ///
+    /// Patterns are handed out in lightness order, so colors that look alike
+    /// in grayscale receive different patterns.
+    pub fn patterns(&self) -> Vec<Pattern> {
+        let lightness: Vec<f32> = self.colors.iter().map(|c| c.to_lab()[0]).collect();
+        let mut ranks: Vec<usize> = (0..lightness.len()).collect();
+        ranks.sort_by(|a, b| lightness[*a].total_cmp(&lightness[*b]));
+
+        let mut patterns = vec![Pattern::Solid; self.colors.len()];
+        for (rank, index) in ranks.into_iter().enumerate() {
+            patterns[index] = Pattern::ALL[rank % Pattern::ALL.len()];
+        }
+
+        patterns
+    }
+
+    /// Returns the colors of the palette paired with their suggested fill
+    /// pattern.
+    pub fn with_patterns(&self) -> Vec<(Color, Pattern)> {
+        self.colors.iter().copied().zip(self.patterns()).collect()
+    }
+
+    /// Compares the palette with another one, slot by slot, reporting the
+    /// colors that changed and by how much.
+    ///
+    /// Parameters:
+    /// * `other`: The newer palette.
+    pub fn diff(&self, other: &Palette) -> PaletteDiff {
+        let shared = self.len().min(other.len());
+
+        let shifted = (0..shared)
+            .filter(|i| self.colors[*i] != other.colors[*i])
+            .map(|i| ColorShift {
+                index: i,
+                from: self.colors[i],
+                to: other.colors[i],
+                delta_e: self.colors[i].delta_e(&other.colors[i]),
+            })
+            .collect();
+
+        PaletteDiff {
+            added: other.colors[shared..].to_vec(),
+            removed: self.colors[shared..].to_vec(),
+            shifted,
+        }
+    }
+
+    /// Returns the palette as Rust source declaring a public constant array
+    /// of RGB colors with the given name.
+    ///
+    /// Parameters:
+    /// * `name`: The name of the constant.
+    pub fn to_rust_source(&self, name: &str) -> String {
+        let mut source = format!("pub const {}: [[u8; 3]; {}] = [\n", name, self.len());
+
+        for color in self.iter() {
+            source.push_str(&format!("    [{}, {}, {}],\n", color.r, color.g, color.b));
+        }
+
+        source.push_str("];\n");
+
+        source
+    }
+
+    /// Returns the palette rendered as terminal color blocks with hex labels,
+    /// one color per line, using 24-bit ANSI escape codes.
+    #[cfg(feature = "ansi_preview")]
+    pub fn preview_string(&self) -> String {
+        self.iter()
+            .map(|color| format!("{}\n", color.preview_string()))
+            .collect()
+    }
+
+    /// Prints the palette to the standard output as terminal color blocks
+    /// with hex labels.
+    #[cfg(feature = "ansi_preview")]
+    pub fn print_preview(&self) {
+        print!("{}", self.preview_string());
+    }
+
+    /// Returns the palette rendered as rows of full Unicode blocks, using
+    /// 24-bit ANSI escape codes, so that each row spans exactly `width`
+    /// terminal columns, e.g. for color pickers in terminal user interfaces.
+    ///
+    /// The width is shared evenly between the colors, giving the leftover
+    /// columns to the first ones. When there are more colors than columns,
+    /// each color takes a single column and the swatches wrap onto further
+    /// rows, the last of which may be shorter. Each row ends with `\n`.
+    ///
+    /// Parameters:
+    /// * `width`: The width of the terminal, in columns.
+    #[cfg(feature = "ansi_preview")]
+    pub fn to_block_rows(&self, width: usize) -> String {
+        let mut rows = String::new();
+
+        if width == 0 {
+            return rows;
+        }
+
+        let wraps = self.colors.len() > width;
+
+        for chunk in self.colors.chunks(width) {
+            for (i, color) in chunk.iter().enumerate() {
+                let columns = match wraps {
+                    true => 1,
+                    false => width / chunk.len() + usize::from(i < width % chunk.len()),
+                };
+
+                rows.push_str(&format!(
+                    "\x1b[38;2;{};{};{}m{}",
+                    color.r,
+                    color.g,
+                    color.b,
+                    "\u{2588}".repeat(columns)
+                ));
+            }
+
+            rows.push_str("\x1b[0m\n");
+        }
+
+        rows
+    }
+
+    /// Returns a self-contained HTML document showing the palette as swatches
+    /// labeled with their hex and HSL values, e.g. for sharing palettes in
+    /// pull request descriptions and documentation.
+    pub fn to_html_preview(&self) -> String {
+        let mut html = String::from(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Palette</title>\n<style>\n\
+             ul { display: flex; flex-wrap: wrap; gap: 16px; padding: 0; list-style: none; font-family: monospace; }\n\
+             li { display: flex; flex-direction: column; gap: 4px; }\n\
+             .swatch { width: 96px; height: 96px; border-radius: 8px; border: 1px solid #0002; }\n\
+             code { user-select: all; }\n\
+             </style>\n</head>\n<body>\n<ul>\n",
+        );
+
+        for color in self.iter() {
+            let hex = color.to_hex();
+            let [h, s, l] = color.to_hsl_array();
+
+            html.push_str(&format!(
+                "<li><div class=\"swatch\" style=\"background: {hex}\"></div><code>{hex}</code><code>hsl({h}, {s}%, {l}%)</code></li>\n",
+                hex = hex,
+                h = h,
+                s = s,
+                l = l,
+            ));
+        }
+
+        html.push_str("</ul>\n</body>\n</html>\n");
+
+        html
+    }
+
+    /// Spreads the CIE L* lightness of the colors evenly between two bounds,
+    /// so the colors stay distinguishable in grayscale.
+    ///
+    /// The lightness order of the colors is preserved and their chromatic
+    /// components are kept, clipping to sRGB where needed.
+    ///
+    /// Parameters:
+    /// * `min`: The lightness of the darkest color, in the range [0, 100].
+    /// * `max`: The lightness of the lightest color, in the range [0, 100].
+    pub fn equalize_lightness(&mut self, min: f32, max: f32) -> &mut Palette {
+        let labs: Vec<[f32; 3]> = self.colors.iter().map(|c| c.to_lab()).collect();
+        let mut ranks: Vec<usize> = (0..labs.len()).collect();
+        ranks.sort_by(|a, b| labs[*a][0].total_cmp(&labs[*b][0]));
+
+        for (rank, index) in ranks.into_iter().enumerate() {
+            let lightness = match labs.len() {
+                1 => (min + max) / 2.0,
+                len => min + (max - min) * rank as f32 / (len - 1) as f32,
+            };
+            let [_, a, b] = labs[index];
+            let [r, g, b] = conversions::lab_to_rgb([lightness.clamp(0.0, 100.0), a, b])
+                .map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+
+            self.colors[index] = Color::new(r, g, b, self.colors[index].a);
+        }
+
+        self
+    }
+}
@@ -0,0 +1,291 @@
+//! Compile-time color generation.
+//!
+//! The functions in this module are `const`, so colors can be generated in
+//! constant contexts and baked into the binary, usually through the
+//! `random_color!` macro.
+//!
+//! They reproduce, step by step, what `RandomColor` does at runtime with the
+//! default dictionary on 64-bit targets. String seeds are hashed with FNV-1a,
+//! so they match runtime string seeds only with the `fast_hash` feature; use
+//! `seed_value` to get the same colors at runtime without it.
+
+use crate::color_dictionary::DEFAULT_COLORS;
+use crate::options::{Gamut, Luminosity};
+
+/// Generates a color at compile time and returns it as an RGB array.
+///
+/// The result is the same as
+/// `RandomColor::new().seed(seed_value(seed)).hue(..).luminosity(..).to_rgb_array()`,
+/// or as seeding with the string itself when the `fast_hash` feature is
+/// enabled.
+///
+/// Parameters:
+/// * `seed`: The seed of the color.
+/// * `hue`: The hue setting.
+/// * `luminosity`: The luminosity setting.
+pub const fn generate_rgb(
+    seed: &str,
+    hue: Option<Gamut>,
+    luminosity: Option<Luminosity>,
+) -> [u8; 3] {
+    let mut rng = ConstRng::seed_from_u64(seed_value(seed));
+
+    let h = match hue {
+        None => rng.random_within(0, 361),
+        Some(gamut) => {
+            let range = DEFAULT_COLORS[gamut as usize].0;
+            rng.random_within(range[0], range[1])
+        }
+    };
+
+    let bounds = color_from_hue(h);
+    let s_min = bounds[0][0];
+    let s_max = bounds[bounds.len() - 1][0];
+    let s = match luminosity {
+        Some(Luminosity::Random) => rng.random_within(0, 100),
+        Some(Luminosity::Bright) => rng.random_within(55, s_max),
+        Some(Luminosity::Dark) => rng.random_within(s_max - 10, s_max),
+        Some(Luminosity::Light) => rng.random_within(s_min, 55),
+        None => rng.random_within(s_min, s_max),
+    };
+
+    let b_min = minimum_value(bounds, s);
+    let b_max = 100;
+    let b = match luminosity {
+        Some(Luminosity::Random) => rng.random_within(0, 100),
+        Some(Luminosity::Light) => rng.random_within((b_max + b_min) / 2, b_max),
+        Some(Luminosity::Dark) => rng.random_within(b_min, b_min + 20),
+        _ => rng.random_within(b_min, b_max),
+    };
+
+    hsv_to_rgb(h, s, b)
+}
+
+/// Returns the numeric seed a string seed is hashed to at compile time, for
+/// generating the same colors at runtime.
+///
+/// Parameters:
+/// * `seed`: The string seed.
+pub const fn seed_value(seed: &str) -> u64 {
+    fnv1a(seed.as_bytes())
+}
+
+/// Hashes bytes with the 64-bit FNV-1a algorithm.
+const fn fnv1a(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash = (hash ^ bytes[i] as u64).wrapping_mul(0x100000001b3);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Returns the lower bounds of the gamut the given hue belongs to.
+const fn color_from_hue(hue: i64) -> &'static [[i64; 2]] {
+    let mut i = 0;
+
+    while i < DEFAULT_COLORS.len() - 1 {
+        let range = DEFAULT_COLORS[i].0;
+
+        if hue >= range[0] && hue <= range[1] {
+            return DEFAULT_COLORS[i].1;
+        }
+
+        i += 1;
+    }
+
+    DEFAULT_COLORS[DEFAULT_COLORS.len() - 1].1
+}
+
+/// Returns the minimum brightness for the given lower bounds and saturation.
+const fn minimum_value(lower_bounds: &[[i64; 2]], saturation: i64) -> i64 {
+    let mut minimum_value = 0;
+    let mut i = 0;
+
+    while i < lower_bounds.len() - 1 {
+        let [s1, v1] = lower_bounds[i];
+        let [s2, v2] = lower_bounds[i + 1];
+
+        if saturation >= s1 && saturation <= s2 {
+            let m = (v2 - v1) / (s2 - s1);
+            let b = v1 - m * s1;
+
+            minimum_value = m * saturation + b;
+        }
+
+        i += 1;
+    }
+
+    minimum_value
+}
+
+/// Rounds a float towards negative infinity.
+const fn floor(value: f32) -> f32 {
+    let truncated = value as i64 as f32;
+
+    if truncated > value {
+        truncated - 1.0
+    } else {
+        truncated
+    }
+}
+
+/// Converts a color from HSV to RGB, like `RandomColor` does.
+const fn hsv_to_rgb(mut hue: i64, saturation: i64, brightness: i64) -> [u8; 3] {
+    if hue == 0 {
+        hue = 1;
+    }
+
+    if hue == 360 {
+        hue = 359;
+    }
+
+    let h: f32 = hue as f32 / 360.0;
+    let s: f32 = saturation as f32 / 100.0;
+    let v: f32 = brightness as f32 / 100.0;
+
+    let h_i = floor(h * 6.0);
+    let f = h * 6.0 - h_i;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - f * s);
+    let t = v * (1.0 - (1.0 - f) * s);
+
+    let (r, g, b) = match h_i as i64 {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+
+    [
+        floor(r * 255.0) as u8,
+        floor(g * 255.0) as u8,
+        floor(b * 255.0) as u8,
+    ]
+}
+
+/// A `const` implementation of the Xoshiro256++ generator behind `SmallRng`
+/// on 64-bit targets.
+struct ConstRng {
+    s: [u64; 4],
+}
+
+impl ConstRng {
+    /// Seeds the generator like `SmallRng::seed_from_u64`, which fills the
+    /// state with PCG32 output.
+    const fn seed_from_u64(mut state: u64) -> ConstRng {
+        let mut s = [0; 4];
+        let mut i = 0;
+
+        while i < 8 {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(11634580027462260723);
+            let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+            let word = xorshifted.rotate_right((state >> 59) as u32) as u64;
+
+            s[i / 2] |= word << (32 * (i % 2));
+            i += 1;
+        }
+
+        if s[0] == 0 && s[1] == 0 && s[2] == 0 && s[3] == 0 {
+            return ConstRng::split_mix(0);
+        }
+
+        ConstRng { s }
+    }
+
+    /// Seeds the generator with SplitMix64, like Xoshiro256++ does for an
+    /// all-zero seed.
+    const fn split_mix(mut state: u64) -> ConstRng {
+        let mut s = [0; 4];
+        let mut i = 0;
+
+        while i < 4 {
+            state = state.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            s[i] = z ^ (z >> 31);
+            i += 1;
+        }
+
+        ConstRng { s }
+    }
+
+    /// Returns the next random `u64`.
+    const fn next_u64(&mut self) -> u64 {
+        let result = self.s[0]
+            .wrapping_add(self.s[3])
+            .rotate_left(23)
+            .wrapping_add(self.s[0]);
+        let t = self.s[1] << 17;
+
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+
+        result
+    }
+
+    /// Generates a random i64 within the given range, like
+    /// `RandomColor::random_within`.
+    const fn random_within(&mut self, mut min: i64, mut max: i64) -> i64 {
+        if min > max {
+            let swap = min;
+            min = max;
+            max = swap;
+        }
+
+        if min == max {
+            max += 1;
+        }
+
+        let range = (max - 1).wrapping_sub(min).wrapping_add(1) as u64;
+        let zone = (range << range.leading_zeros()).wrapping_sub(1);
+
+        loop {
+            let product = self.next_u64() as u128 * range as u128;
+
+            if (product as u64) <= zone {
+                return min.wrapping_add((product >> 64) as i64);
+            }
+        }
+    }
+}
+
+/// Generates a color at compile time and expands to a `[u8; 3]` RGB literal.
+///
+/// The seed must be a string, and the `hue` and `luminosity` settings take
+/// the name of a `Gamut` and `Luminosity` variant respectively.
+///
+/// ```rust
+/// use random_color::random_color;
+///
+/// const ACCENT: [u8; 3] = random_color!("accent", hue = Blue, luminosity = Dark);
+/// ```
+#[macro_export]
+macro_rules! random_color {
+    ($seed:expr $(, hue = $hue:ident)? $(, luminosity = $luminosity:ident)? $(,)?) => {{
+        const COLOR: [u8; 3] = {
+            #[allow(unused_variables)]
+            let hue: Option<$crate::options::Gamut> = None;
+            $(let hue = Some($crate::options::Gamut::$hue);)?
+            #[allow(unused_variables)]
+            let luminosity: Option<$crate::options::Luminosity> = None;
+            $(let luminosity = Some($crate::options::Luminosity::$luminosity);)?
+
+            $crate::const_gen::generate_rgb($seed, hue, luminosity)
+        };
+
+        COLOR
+    }};
+}
@@ -0,0 +1,614 @@
+//! Conversions between the color spaces used by the output methods.
+
+/// The D65 reference white in the CIE XYZ space.
+pub(crate) const D65_WHITE: [f32; 3] = [0.95047, 1.0, 1.08883];
+
+/// The D50 reference white in the CIE XYZ space, as assumed by ICC profiles.
+pub(crate) const D50_WHITE: [f32; 3] = [0.96422, 1.0, 0.82521];
+
+/// The matrix converting CIE XYZ, relative to the D65 white point, to linear
+/// sRGB.
+const XYZ_TO_LINEAR_RGB: [[f32; 3]; 3] = [
+    [3.2404542, -1.5371385, -0.4985314],
+    [-0.969266, 1.8760108, 0.041556],
+    [0.0556434, -0.2040259, 1.0572252],
+];
+
+/// The Bradford matrix converting CIE XYZ to cone responses.
+const BRADFORD: [[f32; 3]; 3] = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// The inverse of the Bradford matrix.
+const BRADFORD_INVERSE: [[f32; 3]; 3] = [
+    [0.9869929, -0.1470543, 0.1599627],
+    [0.4323053, 0.5183603, 0.0492912],
+    [-0.0085287, 0.0400428, 0.9684867],
+];
+
+/// The D65 reference white as an xy chromaticity.
+const D65_CHROMATICITY: [f32; 2] = [0.3127, 0.329];
+
+/// The first wavelength of `SPECTRAL_LOCUS`, in nanometers.
+const SPECTRAL_LOCUS_START: f32 = 380.0;
+
+/// The wavelength step of `SPECTRAL_LOCUS`, in nanometers.
+const SPECTRAL_LOCUS_STEP: f32 = 5.0;
+
+/// The xy chromaticities of the CIE 1931 2° spectral locus, from 380 nm to
+/// 700 nm.
+const SPECTRAL_LOCUS: [[f32; 2]; 65] = [
+    [0.1741, 0.005],
+    [0.174, 0.005],
+    [0.1738, 0.0049],
+    [0.1736, 0.0049],
+    [0.1733, 0.0048],
+    [0.173, 0.0048],
+    [0.1726, 0.0048],
+    [0.1721, 0.0048],
+    [0.1714, 0.0051],
+    [0.1703, 0.0058],
+    [0.1689, 0.0069],
+    [0.1669, 0.0086],
+    [0.1644, 0.0109],
+    [0.1611, 0.0138],
+    [0.1566, 0.0177],
+    [0.151, 0.0227],
+    [0.144, 0.0297],
+    [0.1355, 0.0399],
+    [0.1241, 0.0578],
+    [0.1096, 0.0868],
+    [0.0913, 0.1327],
+    [0.0687, 0.2007],
+    [0.0454, 0.295],
+    [0.0235, 0.4127],
+    [0.0082, 0.5384],
+    [0.0039, 0.6548],
+    [0.0139, 0.7502],
+    [0.0389, 0.812],
+    [0.0743, 0.8338],
+    [0.1142, 0.8262],
+    [0.1547, 0.8059],
+    [0.1929, 0.7816],
+    [0.2296, 0.7543],
+    [0.2658, 0.7243],
+    [0.3016, 0.6923],
+    [0.3373, 0.6589],
+    [0.3731, 0.6245],
+    [0.4087, 0.5896],
+    [0.4441, 0.5547],
+    [0.4788, 0.5202],
+    [0.5125, 0.4866],
+    [0.5448, 0.4544],
+    [0.5752, 0.4242],
+    [0.6029, 0.3965],
+    [0.627, 0.3725],
+    [0.6482, 0.3514],
+    [0.6658, 0.334],
+    [0.6801, 0.3197],
+    [0.6915, 0.3083],
+    [0.7006, 0.2993],
+    [0.7079, 0.292],
+    [0.714, 0.2859],
+    [0.719, 0.2809],
+    [0.723, 0.277],
+    [0.726, 0.274],
+    [0.7283, 0.2717],
+    [0.73, 0.27],
+    [0.7311, 0.2689],
+    [0.732, 0.268],
+    [0.7327, 0.2673],
+    [0.7334, 0.2666],
+    [0.734, 0.266],
+    [0.7344, 0.2656],
+    [0.7346, 0.2654],
+    [0.7347, 0.2653],
+];
+
+/// Converts an sRGB channel in the range [0.0, 1.0] to linear light.
+///
+/// Parameters:
+/// * `channel`: The gamma-encoded channel value.
+pub(crate) fn srgb_to_linear(channel: f32) -> f32 {
+    if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear light channel to an sRGB channel in the range
+/// [0.0, 1.0].
+///
+/// Parameters:
+/// * `channel`: The linear channel value.
+pub(crate) fn linear_to_srgb(channel: f32) -> f32 {
+    if channel <= 0.0031308 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts an HSV color to sRGB with channels in the range [0.0, 1.0],
+/// without any quantization.
+///
+/// Parameters:
+/// * `hsv`: The hue in the range [0.0, 360.0) and the saturation and
+///   brightness in the range [0.0, 1.0].
+pub(crate) fn hsv_to_rgb(hsv: [f32; 3]) -> [f32; 3] {
+    let [h, s, v] = hsv;
+    let f = |n: f32| {
+        let k = (n + h / 60.0).rem_euclid(6.0);
+
+        v - v * s * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+
+    [f(5.0), f(3.0), f(1.0)]
+}
+
+/// Converts an HSV color to HSL, without any quantization.
+///
+/// Parameters:
+/// * `hsv`: The hue in the range [0.0, 360.0) and the saturation and
+///   brightness in the range [0.0, 1.0].
+pub(crate) fn hsv_to_hsl(hsv: [f32; 3]) -> [f32; 3] {
+    let [h, s, v] = hsv;
+    let l = v * (1.0 - s / 2.0);
+
+    let saturation = if l <= 0.0 || l >= 1.0 {
+        0.0
+    } else {
+        (v - l) / l.min(1.0 - l)
+    };
+
+    [h, saturation, l]
+}
+
+/// Converts an sRGB color with channels in the range [0.0, 1.0] to CIE XYZ,
+/// relative to the D65 white point.
+///
+/// Parameters:
+/// * `rgb`: The gamma-encoded sRGB color.
+pub(crate) fn rgb_to_xyz(rgb: [f32; 3]) -> [f32; 3] {
+    let [r, g, b] = rgb.map(srgb_to_linear);
+
+    [
+        0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+        0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+        0.0193339 * r + 0.119192 * g + 0.9503041 * b,
+    ]
+}
+
+/// Converts a CIE XYZ color, relative to the D65 white point, to sRGB.
+///
+/// The channels are not clamped and fall outside the range [0.0, 1.0] for
+/// colors outside the sRGB gamut.
+///
+/// Parameters:
+/// * `xyz`: The XYZ color.
+pub(crate) fn xyz_to_rgb(xyz: [f32; 3]) -> [f32; 3] {
+    let [x, y, z] = xyz;
+
+    XYZ_TO_LINEAR_RGB
+        .map(|[m1, m2, m3]| m1 * x + m2 * y + m3 * z)
+        .map(linear_to_srgb)
+}
+
+/// Multiplies a 3×3 matrix by a vector.
+///
+/// Parameters:
+/// * `matrix`: The matrix, row by row.
+/// * `vector`: The vector.
+pub(crate) fn transform(matrix: &[[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+    let [x, y, z] = vector;
+
+    matrix.map(|[m1, m2, m3]| m1 * x + m2 * y + m3 * z)
+}
+
+/// Adapts a CIE XYZ color from one white point to another with the Bradford
+/// transform, so that the source white maps to the destination white.
+///
+/// Parameters:
+/// * `xyz`: The XYZ color, relative to `from`.
+/// * `from`: The source white point in XYZ.
+/// * `to`: The destination white point in XYZ.
+pub(crate) fn adapt_white_point(xyz: [f32; 3], from: [f32; 3], to: [f32; 3]) -> [f32; 3] {
+    let cone = transform(&BRADFORD, xyz);
+    let source = transform(&BRADFORD, from);
+    let destination = transform(&BRADFORD, to);
+
+    transform(
+        &BRADFORD_INVERSE,
+        [0, 1, 2].map(|i| cone[i] * destination[i] / source[i]),
+    )
+}
+
+/// Converts an sRGB color with channels in the range [0.0, 1.0] to Display
+/// P3, which shares the sRGB white point and transfer function but has wider
+/// primaries.
+///
+/// Parameters:
+/// * `rgb`: The gamma-encoded sRGB color.
+pub(crate) fn rgb_to_display_p3(rgb: [f32; 3]) -> [f32; 3] {
+    let [x, y, z] = rgb_to_xyz(rgb);
+
+    [
+        2.493497 * x - 0.9313836 * y - 0.4027108 * z,
+        -0.829489 * x + 1.7626641 * y + 0.0236247 * z,
+        0.0358458 * x - 0.0761724 * y + 0.9568845 * z,
+    ]
+    .map(linear_to_srgb)
+}
+
+/// Converts an sRGB color with channels in the range [0.0, 1.0] to Rec.2020,
+/// encoded with the BT.2020 transfer function.
+///
+/// Parameters:
+/// * `rgb`: The gamma-encoded sRGB color.
+pub(crate) fn rgb_to_rec2020(rgb: [f32; 3]) -> [f32; 3] {
+    let [x, y, z] = rgb_to_xyz(rgb);
+
+    [
+        1.7166512 * x - 0.3556708 * y - 0.2533663 * z,
+        -0.6666844 * x + 1.6164812 * y + 0.0157685 * z,
+        0.0176399 * x - 0.0427706 * y + 0.9421031 * z,
+    ]
+    .map(|channel| {
+        if channel < 0.018053968 {
+            channel * 4.5
+        } else {
+            1.0992968 * channel.powf(0.45) - 0.0992968
+        }
+    })
+}
+
+/// Converts a CIE XYZ color to CIE L*a*b*.
+///
+/// Parameters:
+/// * `xyz`: The XYZ color.
+/// * `white`: The reference white of the XYZ color.
+pub(crate) fn xyz_to_lab(xyz: [f32; 3], white: [f32; 3]) -> [f32; 3] {
+    let f = |t: f32| {
+        if t > 216.0 / 24389.0 {
+            t.cbrt()
+        } else {
+            (24389.0 / 27.0 * t + 16.0) / 116.0
+        }
+    };
+
+    let fx = f(xyz[0] / white[0]);
+    let fy = f(xyz[1] / white[1]);
+    let fz = f(xyz[2] / white[2]);
+
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Converts an sRGB color with channels in the range [0.0, 1.0] to CIE
+/// L*a*b*, relative to the D65 white point.
+///
+/// Parameters:
+/// * `rgb`: The gamma-encoded sRGB color.
+pub(crate) fn rgb_to_lab(rgb: [f32; 3]) -> [f32; 3] {
+    xyz_to_lab(rgb_to_xyz(rgb), D65_WHITE)
+}
+
+/// Converts a CIE L*a*b* color to CIE XYZ.
+///
+/// Parameters:
+/// * `lab`: The L*a*b* color.
+/// * `white`: The reference white of the L*a*b* color.
+pub(crate) fn lab_to_xyz(lab: [f32; 3], white: [f32; 3]) -> [f32; 3] {
+    let f_inv = |t: f32| {
+        if t.powi(3) > 216.0 / 24389.0 {
+            t.powi(3)
+        } else {
+            (116.0 * t - 16.0) * 27.0 / 24389.0
+        }
+    };
+
+    let fy = (lab[0] + 16.0) / 116.0;
+    let fx = fy + lab[1] / 500.0;
+    let fz = fy - lab[2] / 200.0;
+
+    [
+        f_inv(fx) * white[0],
+        f_inv(fy) * white[1],
+        f_inv(fz) * white[2],
+    ]
+}
+
+/// Converts a CIE L*a*b* color, relative to the D65 white point, to sRGB.
+///
+/// Parameters:
+/// * `lab`: The L*a*b* color.
+pub(crate) fn lab_to_rgb(lab: [f32; 3]) -> [f32; 3] {
+    xyz_to_rgb(lab_to_xyz(lab, D65_WHITE))
+}
+
+/// Converts an sRGB color with channels in the range [0.0, 1.0] to OKLab.
+///
+/// Parameters:
+/// * `rgb`: The gamma-encoded sRGB color.
+pub(crate) fn rgb_to_oklab(rgb: [f32; 3]) -> [f32; 3] {
+    let [r, g, b] = rgb.map(srgb_to_linear);
+
+    let l = (0.41222146 * r + 0.53633255 * g + 0.051445995 * b).cbrt();
+    let m = (0.2119035 * r + 0.6806995 * g + 0.10739696 * b).cbrt();
+    let s = (0.08830246 * r + 0.28171885 * g + 0.6299787 * b).cbrt();
+
+    [
+        0.21045426 * l + 0.7936178 * m - 0.004072047 * s,
+        1.9779985 * l - 2.4285922 * m + 0.4505937 * s,
+        0.025904037 * l + 0.78277177 * m - 0.80867577 * s,
+    ]
+}
+
+/// Converts an OKLab color to sRGB, with channels in the range [0.0, 1.0].
+///
+/// The result is not clipped, so colors outside of the sRGB gamut have
+/// channels outside of that range.
+///
+/// Parameters:
+/// * `lab`: The OKLab color.
+pub(crate) fn oklab_to_rgb(lab: [f32; 3]) -> [f32; 3] {
+    let [lightness, a, b] = lab;
+
+    let l = (lightness + 0.39633778 * a + 0.21580376 * b).powi(3);
+    let m = (lightness - 0.105561346 * a - 0.06385417 * b).powi(3);
+    let s = (lightness - 0.08948418 * a - 1.2914855 * b).powi(3);
+
+    [
+        4.0767417 * l - 3.3077116 * m + 0.23096994 * s,
+        -1.268438 * l + 2.6097574 * m - 0.3413194 * s,
+        -0.0041960864 * l - 0.7034186 * m + 1.7076147 * s,
+    ]
+    .map(linear_to_srgb)
+}
+
+/// The lowest reflectance used in Kubelka–Munk mixing, which keeps the
+/// absorption-to-scattering ratio of black channels finite.
+#[cfg(feature = "pigment_mixing")]
+const MIN_REFLECTANCE: f32 = 1e-3;
+
+/// Mixes two sRGB colors like pigments, with a single-constant Kubelka–Munk
+/// model applied to each linear channel as a reflectance.
+///
+/// Parameters:
+/// * `a`: The first color, with channels in the range [0.0, 1.0].
+/// * `b`: The second color, with channels in the range [0.0, 1.0].
+/// * `t`: The proportion of `b`, in the range [0.0, 1.0].
+#[cfg(feature = "pigment_mixing")]
+pub(crate) fn pigment_mix(a: [f32; 3], b: [f32; 3], t: f32) -> [f32; 3] {
+    let absorption = |channel: f32| {
+        let reflectance = srgb_to_linear(channel).max(MIN_REFLECTANCE);
+
+        (1.0 - reflectance).powi(2) / (2.0 * reflectance)
+    };
+
+    [0, 1, 2].map(|c| {
+        let ks = absorption(a[c]) * (1.0 - t) + absorption(b[c]) * t;
+
+        linear_to_srgb(1.0 + ks - (ks * ks + 2.0 * ks).sqrt())
+    })
+}
+
+/// Converts a rectangular color, such as L*a*b* or OKLab, to its cylindrical
+/// form, with the hue in degrees in the range [0.0, 360.0).
+///
+/// Parameters:
+/// * `lab`: The lightness and the two opponent axes of the color.
+pub(crate) fn lab_to_lch(lab: [f32; 3]) -> [f32; 3] {
+    let [l, a, b] = lab;
+
+    [l, a.hypot(b), b.atan2(a).to_degrees().rem_euclid(360.0)]
+}
+
+/// The approximate CIE LCH(ab) hue angles of the ten principal Munsell hues,
+/// 5R, 5YR, 5Y, 5GY, 5G, 5BG, 5B, 5PB, 5P and 5RP, in degrees.
+const MUNSELL_HUE_ANGLES: [f32; 10] = [
+    20.0, 55.0, 88.0, 115.0, 160.0, 200.0, 235.0, 280.0, 315.0, 350.0,
+];
+
+/// Approximates the Munsell coordinates of a CIE LCH(ab) color, returning the
+/// hue on the 100-step Munsell circle, where 5R is 5.0 and 5RP is 95.0, the
+/// value in the range [0.0, 10.0] and the chroma.
+///
+/// The hue is interpolated between the principal hues, the value is L* / 10
+/// and the chroma is C* / 5, so the result is only an approximation of the
+/// Munsell renotation data.
+///
+/// Parameters:
+/// * `lch`: The LCH(ab) color, with the hue in degrees.
+pub(crate) fn lch_to_munsell(lch: [f32; 3]) -> [f32; 3] {
+    let [l, c, h] = lch;
+    let h = h.rem_euclid(360.0);
+
+    let hue = (0..MUNSELL_HUE_ANGLES.len())
+        .find_map(|i| {
+            let from = MUNSELL_HUE_ANGLES[i];
+            let to = match MUNSELL_HUE_ANGLES.get(i + 1) {
+                Some(to) => *to,
+                None => MUNSELL_HUE_ANGLES[0] + 360.0,
+            };
+            let angle = match h < from {
+                true => h + 360.0,
+                false => h,
+            };
+
+            (angle >= from && angle < to)
+                .then(|| 5.0 + 10.0 * (i as f32 + (angle - from) / (to - from)))
+        })
+        .unwrap_or(5.0);
+
+    [hue.rem_euclid(100.0), (l / 10.0).clamp(0.0, 10.0), c / 5.0]
+}
+
+/// Converts an sRGB color with channels in the range [0.0, 1.0] to HSLuv,
+/// with the hue in degrees and the saturation and lightness in the range
+/// [0.0, 100.0].
+///
+/// Parameters:
+/// * `rgb`: The gamma-encoded sRGB color.
+pub(crate) fn rgb_to_hsluv(rgb: [f32; 3]) -> [f32; 3] {
+    let [l, c, h] = lab_to_lch(xyz_to_luv(rgb_to_xyz(rgb)));
+
+    if l > 99.9999 {
+        return [h, 0.0, 100.0];
+    }
+
+    if l < 0.0001 {
+        return [h, 0.0, 0.0];
+    }
+
+    [h, (c / max_chroma(l, h) * 100.0).min(100.0), l]
+}
+
+/// Converts a CIE XYZ color, relative to the D65 white point, to CIE L*u*v*.
+///
+/// Parameters:
+/// * `xyz`: The XYZ color.
+fn xyz_to_luv(xyz: [f32; 3]) -> [f32; 3] {
+    let l = match xyz[1] / D65_WHITE[1] {
+        y if y > 216.0 / 24389.0 => 116.0 * y.cbrt() - 16.0,
+        y => 24389.0 / 27.0 * y,
+    };
+
+    if l == 0.0 {
+        return [0.0, 0.0, 0.0];
+    }
+
+    let [u_white, v_white] = uv_chromaticity(D65_WHITE);
+    let [u, v] = uv_chromaticity(xyz);
+
+    [l, 13.0 * l * (u - u_white), 13.0 * l * (v - v_white)]
+}
+
+/// Returns the u' and v' chromaticity coordinates of a CIE XYZ color.
+///
+/// Parameters:
+/// * `xyz`: The XYZ color.
+fn uv_chromaticity(xyz: [f32; 3]) -> [f32; 2] {
+    let [x, y, z] = xyz;
+    let divider = x + 15.0 * y + 3.0 * z;
+
+    [4.0 * x / divider, 9.0 * y / divider]
+}
+
+/// Returns the maximum CIE LCh(uv) chroma inside the sRGB gamut for the
+/// given lightness and hue.
+///
+/// Parameters:
+/// * `l`: The lightness, in the range [0.0, 100.0].
+/// * `h`: The hue, in degrees.
+fn max_chroma(l: f32, h: f32) -> f32 {
+    let (sin, cos) = h.to_radians().sin_cos();
+    let sub1 = (l + 16.0).powi(3) / 1560896.0;
+    let sub2 = match sub1 > 216.0 / 24389.0 {
+        true => sub1,
+        false => l / (24389.0 / 27.0),
+    };
+
+    let mut max = f32::INFINITY;
+
+    for [m1, m2, m3] in XYZ_TO_LINEAR_RGB {
+        for t in [0.0, 1.0] {
+            let top1 = (284517.0 * m1 - 94839.0 * m3) * sub2;
+            let top2 =
+                (838422.0 * m3 + 769860.0 * m2 + 731718.0 * m1) * l * sub2 - 769860.0 * t * l;
+            let bottom = (632260.0 * m3 - 126452.0 * m2) * sub2 + 126452.0 * t;
+
+            let length = (top2 / bottom) / (sin - top1 / bottom * cos);
+
+            if length >= 0.0 {
+                max = max.min(length);
+            }
+        }
+    }
+
+    max
+}
+
+/// Returns the dominant wavelength, in nanometers, and the excitation purity,
+/// in the range [0.0, 1.0], of a CIE XYZ color relative to the D65 white
+/// point, or `None` for achromatic colors.
+///
+/// Purples have no dominant wavelength, so their complementary wavelength is
+/// returned as a negative value instead, and their purity is measured
+/// against the line of purples.
+///
+/// Parameters:
+/// * `xyz`: The XYZ color.
+pub(crate) fn dominant_wavelength(xyz: [f32; 3]) -> Option<(f32, f32)> {
+    let sum = xyz[0] + xyz[1] + xyz[2];
+    if sum <= 0.0 {
+        return None;
+    }
+
+    let [wx, wy] = D65_CHROMATICITY;
+    let direction = [xyz[0] / sum - wx, xyz[1] / sum - wy];
+
+    if direction[0].hypot(direction[1]) < 1e-4 {
+        return None;
+    }
+
+    let (index, t) = locus_intersection(direction)?;
+
+    if index < SPECTRAL_LOCUS.len() - 1 {
+        return Some((locus_wavelength(index, direction, t), 1.0 / t));
+    }
+
+    let opposite = [-direction[0], -direction[1]];
+    let (index, t_opposite) = locus_intersection(opposite)?;
+
+    Some((-locus_wavelength(index, opposite, t_opposite), 1.0 / t))
+}
+
+/// Intersects a ray from the D65 white point with the spectral locus closed
+/// by the line of purples, returning the index of the segment hit and the
+/// ray parameter of the intersection. The last segment is the line of
+/// purples.
+///
+/// Parameters:
+/// * `direction`: The direction of the ray in the xy plane.
+fn locus_intersection(direction: [f32; 2]) -> Option<(usize, f32)> {
+    let [wx, wy] = D65_CHROMATICITY;
+    let [dx, dy] = direction;
+
+    (0..SPECTRAL_LOCUS.len()).find_map(|i| {
+        let [ax, ay] = SPECTRAL_LOCUS[i];
+        let [bx, by] = SPECTRAL_LOCUS[(i + 1) % SPECTRAL_LOCUS.len()];
+        let [ex, ey] = [bx - ax, by - ay];
+
+        let denominator = dx * ey - dy * ex;
+        if denominator.abs() < f32::EPSILON {
+            return None;
+        }
+
+        let t = ((ax - wx) * ey - (ay - wy) * ex) / denominator;
+        let u = ((ax - wx) * dy - (ay - wy) * dx) / denominator;
+
+        (t > 0.0 && (0.0..=1.0).contains(&u)).then_some((i, t))
+    })
+}
+
+/// Returns the wavelength of the point where a ray from the D65 white point
+/// hits a segment of the spectral locus.
+///
+/// Parameters:
+/// * `index`: The index of the segment.
+/// * `direction`: The direction of the ray in the xy plane.
+/// * `t`: The ray parameter of the intersection.
+fn locus_wavelength(index: usize, direction: [f32; 2], t: f32) -> f32 {
+    let [wx, wy] = D65_CHROMATICITY;
+    let [ax, ay] = SPECTRAL_LOCUS[index];
+    let [bx, by] = SPECTRAL_LOCUS[index + 1];
+    let point = [wx + direction[0] * t, wy + direction[1] * t];
+
+    let length = (bx - ax).hypot(by - ay);
+    let local = (point[0] - ax).hypot(point[1] - ay) / length;
+
+    SPECTRAL_LOCUS_START + (index as f32 + local) * SPECTRAL_LOCUS_STEP
+}
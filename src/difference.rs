@@ -0,0 +1,149 @@
+//! Perceptual color difference formulas.
+
+/// Computes the CIEDE2000 color difference between two CIE L*a*b* colors.
+///
+/// Parameters:
+/// * `lab1`: The first color.
+/// * `lab2`: The second color.
+pub(crate) fn ciede2000(lab1: [f32; 3], lab2: [f32; 3]) -> f32 {
+    let [l1, a1, b1] = lab1.map(f64::from);
+    let [l2, a2, b2] = lab2.map(f64::from);
+
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let c_mean = (c1 + c2) / 2.0;
+
+    let g = 0.5 * (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt());
+    let a1p = a1 * (1.0 + g);
+    let a2p = a2 * (1.0 + g);
+
+    let c1p = a1p.hypot(b1);
+    let c2p = a2p.hypot(b2);
+
+    let hue = |b: f64, a: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let h1p = hue(b1, a1p);
+    let h2p = hue(b2, a2p);
+
+    let dl = l2 - l1;
+    let dc = c2p - c1p;
+    let dh = if c1p * c2p == 0.0 {
+        0.0
+    } else if (h2p - h1p).abs() <= 180.0 {
+        h2p - h1p
+    } else if h2p - h1p > 180.0 {
+        h2p - h1p - 360.0
+    } else {
+        h2p - h1p + 360.0
+    };
+    let dh = 2.0 * (c1p * c2p).sqrt() * (dh / 2.0).to_radians().sin();
+
+    let l_mean = (l1 + l2) / 2.0;
+    let cp_mean = (c1p + c2p) / 2.0;
+    let hp_mean = if c1p * c2p == 0.0 {
+        h1p + h2p
+    } else if (h1p - h2p).abs() <= 180.0 {
+        (h1p + h2p) / 2.0
+    } else if h1p + h2p < 360.0 {
+        (h1p + h2p + 360.0) / 2.0
+    } else {
+        (h1p + h2p - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * (hp_mean - 30.0).to_radians().cos()
+        + 0.24 * (2.0 * hp_mean).to_radians().cos()
+        + 0.32 * (3.0 * hp_mean + 6.0).to_radians().cos()
+        - 0.20 * (4.0 * hp_mean - 63.0).to_radians().cos();
+
+    let d_theta = 30.0 * (-((hp_mean - 275.0) / 25.0).powi(2)).exp();
+    let rc = 2.0 * (cp_mean.powi(7) / (cp_mean.powi(7) + 25f64.powi(7))).sqrt();
+    let sl = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+    let sc = 1.0 + 0.045 * cp_mean;
+    let sh = 1.0 + 0.015 * cp_mean * t;
+    let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+    let dl = dl / sl;
+    let dc = dc / sc;
+    let dh = dh / sh;
+
+    (dl * dl + dc * dc + dh * dh + rt * dc * dh).sqrt() as f32
+}
+
+/// Computes the CIE76 color difference between two CIE L*a*b* colors, that
+/// is, their Euclidean distance.
+///
+/// Parameters:
+/// * `lab1`: The first color.
+/// * `lab2`: The second color.
+pub(crate) fn cie76(lab1: [f32; 3], lab2: [f32; 3]) -> f32 {
+    let [dl, da, db] = [0, 1, 2].map(|i| lab1[i] - lab2[i]);
+
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// Computes the CIE94 color difference of a CIE L*a*b* color from a
+/// reference one.
+///
+/// Parameters:
+/// * `reference`: The reference color.
+/// * `sample`: The color compared against the reference.
+/// * `kl`: The lightness weight, 1.0 for graphic arts and 2.0 for textiles.
+/// * `k1`: The chroma weight, 0.045 for graphic arts and 0.048 for textiles.
+/// * `k2`: The hue weight, 0.015 for graphic arts and 0.014 for textiles.
+pub(crate) fn cie94(reference: [f32; 3], sample: [f32; 3], kl: f32, k1: f32, k2: f32) -> f32 {
+    let (dl, dc, dh_squared, c1) = lch_differences(reference, sample);
+
+    let sc = 1.0 + k1 * c1;
+    let sh = 1.0 + k2 * c1;
+
+    ((dl / kl).powi(2) + (dc / sc).powi(2) + dh_squared / (sh * sh)).sqrt()
+}
+
+/// Computes the CMC l:c color difference of a CIE L*a*b* color from a
+/// reference one.
+///
+/// Parameters:
+/// * `reference`: The reference color.
+/// * `sample`: The color compared against the reference.
+/// * `l`: The lightness weight, usually 2.0 for acceptability and 1.0 for
+///   perceptibility.
+/// * `c`: The chroma weight, usually 1.0.
+pub(crate) fn cmc(reference: [f32; 3], sample: [f32; 3], l: f32, c: f32) -> f32 {
+    let (dl, dc, dh_squared, c1) = lch_differences(reference, sample);
+    let [l1, a1, b1] = reference;
+
+    let sl = match l1 < 16.0 {
+        true => 0.511,
+        false => 0.040975 * l1 / (1.0 + 0.01765 * l1),
+    };
+    let sc = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+
+    let h1 = b1.atan2(a1).to_degrees().rem_euclid(360.0);
+    let t = match (164.0..=345.0).contains(&h1) {
+        true => 0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs(),
+        false => 0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs(),
+    };
+    let f = (c1.powi(4) / (c1.powi(4) + 1900.0)).sqrt();
+    let sh = sc * (f * t + 1.0 - f);
+
+    ((dl / (l * sl)).powi(2) + (dc / (c * sc)).powi(2) + dh_squared / (sh * sh)).sqrt()
+}
+
+/// Returns the lightness difference, the chroma difference, the squared hue
+/// difference and the reference chroma of two CIE L*a*b* colors.
+fn lch_differences(reference: [f32; 3], sample: [f32; 3]) -> (f32, f32, f32, f32) {
+    let [l1, a1, b1] = reference;
+    let [l2, a2, b2] = sample;
+
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let dc = c1 - c2;
+    let dh_squared = ((a1 - a2).powi(2) + (b1 - b2).powi(2) - dc * dc).max(0.0);
+
+    (l1 - l2, dc, dh_squared, c1)
+}
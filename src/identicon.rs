@@ -0,0 +1,109 @@
+/// The number of cells on each side of an identicon.
+pub const IDENTICON_SIZE: usize = 5;
+
+/// A 5×5 horizontally symmetric identicon.
+///
+/// Identicons are usually created through `RandomColor::to_identicon`, which
+/// picks the cell pattern and both colors from the generator's seed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Identicon {
+    /// The filled cells of the identicon, indexed by row and then column.
+    pub cells: [[bool; IDENTICON_SIZE]; IDENTICON_SIZE],
+    /// The RGB color of the filled cells.
+    pub foreground: [u8; 3],
+    /// The RGB color of the empty cells.
+    pub background: [u8; 3],
+}
+
+impl Identicon {
+    /// Creates a new `Identicon` instance.
+    ///
+    /// Only the left half of each row (including the middle column) is read
+    /// from `pattern`; the right half is mirrored from it.
+    ///
+    /// Parameters:
+    /// * `pattern`: The filled cells of the identicon.
+    /// * `foreground`: The RGB color of the filled cells.
+    /// * `background`: The RGB color of the empty cells.
+    pub fn new(
+        pattern: [[bool; IDENTICON_SIZE]; IDENTICON_SIZE],
+        foreground: [u8; 3],
+        background: [u8; 3],
+    ) -> Identicon {
+        let mut cells = pattern;
+
+        for row in cells.iter_mut() {
+            for column in 0..IDENTICON_SIZE / 2 {
+                row[IDENTICON_SIZE - 1 - column] = row[column];
+            }
+        }
+
+        Identicon {
+            cells,
+            foreground,
+            background,
+        }
+    }
+
+    /// Renders the identicon into a tightly packed RGBA buffer.
+    ///
+    /// The resulting image is `5 * cell_size` pixels wide and high.
+    ///
+    /// Parameters:
+    /// * `cell_size`: The width and height in pixels of each cell.
+    pub fn to_rgba_buffer(&self, cell_size: usize) -> Vec<u8> {
+        let side = IDENTICON_SIZE * cell_size;
+        let mut buffer = Vec::with_capacity(side * side * 4);
+
+        for y in 0..side {
+            for x in 0..side {
+                let [r, g, b] = match self.cells[y / cell_size][x / cell_size] {
+                    true => self.foreground,
+                    false => self.background,
+                };
+
+                buffer.extend_from_slice(&[r, g, b, 255]);
+            }
+        }
+
+        buffer
+    }
+
+    /// Renders the identicon as an SVG document.
+    ///
+    /// Parameters:
+    /// * `size`: The width and height in pixels of the SVG image.
+    pub fn to_svg(&self, size: u32) -> String {
+        let [br, bg, bb] = self.background;
+        let [fr, fg, fb] = self.foreground;
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{size}\" height=\"{size}\" viewBox=\"0 0 {side} {side}\" shape-rendering=\"crispEdges\">",
+            size = size,
+            side = IDENTICON_SIZE,
+        );
+
+        svg.push_str(&format!(
+            "<rect width=\"{side}\" height=\"{side}\" fill=\"#{:02x}{:02x}{:02x}\"/>",
+            br,
+            bg,
+            bb,
+            side = IDENTICON_SIZE,
+        ));
+
+        for (y, row) in self.cells.iter().enumerate() {
+            for (x, filled) in row.iter().enumerate() {
+                if *filled {
+                    svg.push_str(&format!(
+                        "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\" fill=\"#{:02x}{:02x}{:02x}\"/>",
+                        x, y, fr, fg, fb
+                    ));
+                }
+            }
+        }
+
+        svg.push_str("</svg>");
+
+        svg
+    }
+}
@@ -36,6 +36,10 @@
 //! let color = random_color.to_rgb_string();
 //! println!("{}", color);
 //! ```
+//!
+//! Ready-made helpers for common tasks, such as exporting palettes or picking
+//! avatar colors, live in the `showcase` module, and the `examples` directory
+//! runs them.
 #[cfg(feature = "ecolor_support")]
 extern crate ecolor;
 #[cfg(feature = "palette_support")]
@@ -43,46 +47,213 @@ extern crate palette;
 extern crate rand;
 #[cfg(feature = "rgb_support")]
 extern crate rgb;
+#[cfg(feature = "tracing_support")]
+extern crate tracing;
+
+/// Emits a `tracing` debug event when the `tracing_support` feature is
+/// enabled, and nothing otherwise.
+macro_rules! trace_event {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "tracing_support")]
+        tracing::debug!($($arg)*);
+    };
+}
 
+pub mod assigner;
+pub mod audit;
+pub mod build_support;
+pub mod canonical;
+pub mod color;
 pub mod color_dictionary;
+pub mod color_palette;
+#[cfg(feature = "const_generation")]
+pub mod const_gen;
+mod conversions;
+mod difference;
+pub mod identicon;
 pub mod options;
-
+pub mod presets;
+pub mod session;
+pub mod showcase;
+#[cfg(feature = "soft_proofing")]
+pub mod soft_proof;
+pub mod strategy;
+pub mod test_vectors;
+#[cfg(feature = "textures")]
+pub mod texture;
+pub mod ui;
+pub mod value_mapper;
+
+use audit::ConversionAudit;
+use canonical::{CanonicalParseError, CanonicalRecord};
+use color::{Color, HexParseError};
 use color_dictionary::ColorDictionary;
+use color_palette::Palette;
 #[cfg(feature = "ecolor_support")]
 use ecolor::{Color32, Rgba};
-use options::{Gamut, Luminosity, Seed};
+use identicon::{Identicon, IDENTICON_SIZE};
+use options::{
+    AlgorithmVersion, AlphaError, Candidate, CandidateFilter, Colormap, CssFormat, Curve, Fallback,
+    Gamut, GamutMapping, GamutRotation, Luminosity, LuminosityBlend, Mix, Near, RetryPolicy,
+    Sampling, Season, SeasonalWeights, Seed, Style, ValueRange, VertexLayout, WhiteExtraction,
+    YCbCrStandard,
+};
 #[cfg(feature = "palette_support")]
 use palette::{Srgb, Srgba};
+use presets::Presets;
 use rand::rngs::SmallRng;
 use rand::{Rng, SeedableRng};
 #[cfg(feature = "rgb_support")]
 use rgb::Rgb;
+#[cfg(feature = "soft_proofing")]
+use soft_proof::DisplayProfile;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use strategy::{PickStrategy, SharedPickStrategy};
+use ui::{LuminositySet, UiStateColors};
+use value_mapper::ValueMapper;
+
+/// The maximum shift applied to the a* and b* components of colormap
+/// anchors when perturbing them.
+const COLORMAP_JITTER: f32 = 6.0;
+
+/// The maximum distance above the dictionary's lower bounds at which edge
+/// sampling picks the brightness.
+const EDGE_TOLERANCE: i64 = 5;
+
+/// The gamma exponent used when correcting colors for addressable LEDs.
+const LED_GAMMA: f32 = 2.8;
 
 /// A structure for generating random colors with a variety of options.
 ///
 /// The available options are:
 /// * `hue`: Specify a specific hue, or a range of hues. You can use the
 ///   `Gamut` enum to select a hue.
+/// * `exact_hue`: Pin the hue to a value in degrees, while the saturation
+///   and brightness are still picked from the dictionary for that hue. When
+///   set, it takes precedence over every other hue setting but `near`,
+///   including `hue_mask`.
 /// * `luminosity`: Specify a specific luminosity, or a range of luminosities.
 ///   You can use the `Luminosity` enum to select a luminosity.
+/// * `luminosity_blend`: Specify a blend between two luminosities. When set,
+///   it takes precedence over `luminosity`.
+/// * `hue_mask`: Specify the allowed hue degrees. When set, hues are only
+///   picked among the allowed degrees of the range the other hue settings
+///   select, or among every allowed degree if there are none. It does not
+///   apply to `exact_hue`.
+/// * `style`: Specify an aesthetic style, such as pastel or neon. When set,
+///   it takes precedence over `luminosity` and `luminosity_blend`.
+/// * `saturation`: Specify a saturation value or range, in the range
+///   [0, 100]. When set, it takes precedence over the saturation range of
+///   the hue and over `luminosity`.
+/// * `brightness`: Specify a brightness value or range, in the range
+///   [0, 100]. When set, it takes precedence over the brightness range of
+///   the hue and saturation, over edge sampling and over `luminosity`.
+/// * `saturation_bias`: Specify an easing curve reshaping how saturation
+///   values are sampled within their range. If you don't specify a curve,
+///   saturation values will be sampled uniformly.
+/// * `value_bias`: Specify an easing curve reshaping how brightness values
+///   are sampled within their range. If you don't specify a curve,
+///   brightness values will be sampled uniformly.
 /// * `seed`: Specify a seed for the random number generator. If you don't
 ///   specify a seed, one will be generated randomly.
 /// * `alpha`: Specify an alpha value for the generated color. If you don't
 ///   specify an alpha value, 1.0 will be used.
 /// * `color_dictionary`: Specify a custom color dictionary. If you don't
 ///   specify a color dictionary, the default one will be used.
+/// * `gamut_weights`: Specify several hues with relative weights. When set,
+///   it takes precedence over `hue`.
+/// * `gamut_rotation`: Specify several hues that successive colors rotate
+///   through in order. When set, it takes precedence over `gamut_weights`
+///   and `hue`.
+/// * `near`: Restrict generation to a neighborhood around a reference color.
+///   When set, it takes precedence over `hue` and `luminosity`.
+/// * `min_lightness_gap`: Specify the minimum CIE L* lightness difference
+///   between colors generated in batches, such as palettes. The gap is
+///   best-effort: colors that cannot satisfy it within the retry policy
+///   fall back to its fallback.
+/// * `channel_jitter`: Specify the maximum random offset applied to each RGB
+///   channel by `jitter`.
+/// * `grayscale`: Force every generated color to be a gray with the
+///   brightness of the picked color.
+/// * `retry_policy`: Specify how many candidates constrained generation tries
+///   and what it falls back to. If you don't specify a retry policy, the
+///   default one will be used.
+/// * `sampling`: Specify whether colors are sampled from anywhere inside the
+///   dictionary's acceptable region, or close to its edge. If you don't
+///   specify a sampling mode, interior sampling will be used.
+/// * `candidate_filter`: Specify a custom rule accepting, rejecting or
+///   adjusting every generated candidate.
+/// * `mix`: Sample colors from one of two generators, picked at random for
+///   every color. When set, it takes precedence over every other setting
+///   used to pick the hue, saturation and brightness.
+/// * `algorithm_version`: Specify the version of the conversion algorithms.
+///   If you don't specify a version, the original one will be used so that
+///   seeds keep producing the same colors.
+/// * `pick_strategy`: Specify custom heuristics for picking the hue,
+///   saturation and brightness. If you don't specify a strategy, the default
+///   one will be used.
+/// * `default_format`: Specify the CSS syntax used when displaying the
+///   generator with `{}`. If you don't specify a format, hex will be used.
+/// * `gamut_mapping`: Specify how colors computed outside the sRGB gamut,
+///   such as colormaps interpolated in Lab, are brought into it. If you
+///   don't specify a mapping, the channels will be clipped.
 #[derive(Debug, PartialEq, Clone)]
 pub struct RandomColor {
     /// The hue of the color to generate.
     pub hue: Option<Gamut>,
+    /// The exact hue of the color to generate, in degrees.
+    pub exact_hue: Option<i64>,
     /// The luminosity of the color to generate.
     pub luminosity: Option<Luminosity>,
+    /// The blend between two luminosities of the color to generate.
+    pub luminosity_blend: Option<LuminosityBlend>,
+    /// The aesthetic style of the color to generate.
+    pub style: Option<Style>,
+    /// The allowed hue degrees of the color to generate.
+    pub hue_mask: Option<Box<[bool; 360]>>,
+    /// The saturation of the color to generate.
+    pub saturation: Option<ValueRange>,
+    /// The brightness of the color to generate.
+    pub brightness: Option<ValueRange>,
+    /// The easing curve saturation values are sampled with.
+    pub saturation_bias: Option<Curve>,
+    /// The easing curve brightness values are sampled with.
+    pub value_bias: Option<Curve>,
     /// The seed for the random number generator.
     pub seed: SmallRng,
     /// The alpha value of the color to generate.
     pub alpha: Option<f32>,
     /// The color dictionary to use.
     pub color_dictionary: ColorDictionary,
+    /// The weighted hues of the color to generate.
+    pub gamut_weights: Option<Vec<(Gamut, u32)>>,
+    /// The hues successive colors rotate through.
+    pub gamut_rotation: Option<GamutRotation>,
+    /// The neighborhood of the color to generate.
+    pub near: Option<Near>,
+    /// The minimum lightness difference between colors generated in batches.
+    pub min_lightness_gap: Option<f32>,
+    /// The maximum random offset of each RGB channel applied by `jitter`.
+    pub channel_jitter: Option<[u8; 3]>,
+    /// Whether generated colors are forced to grays.
+    pub grayscale: bool,
+    /// The retry policy of constrained generation.
+    pub retry_policy: RetryPolicy,
+    /// Where colors are sampled in the dictionary's acceptable region.
+    pub sampling: Sampling,
+    /// The custom rule applied to every generated candidate.
+    pub candidate_filter: Option<CandidateFilter>,
+    /// The weighted mix of generators colors are sampled from.
+    pub mix: Option<Box<Mix>>,
+    /// The version of the conversion algorithms.
+    pub algorithm_version: AlgorithmVersion,
+    /// The strategy used to pick the hue, saturation and brightness.
+    pub pick_strategy: Option<SharedPickStrategy>,
+    /// The CSS syntax used by the `Display` implementation.
+    pub default_format: CssFormat,
+    /// How colors outside the sRGB gamut are brought into it.
+    pub gamut_mapping: GamutMapping,
 }
 
 impl RandomColor {
@@ -90,11 +261,223 @@ impl RandomColor {
     pub fn new() -> Self {
         RandomColor {
             hue: None,
+            exact_hue: None,
             luminosity: None,
+            luminosity_blend: None,
+            style: None,
+            hue_mask: None,
+            saturation: None,
+            brightness: None,
+            saturation_bias: None,
+            value_bias: None,
             seed: SmallRng::from_entropy(),
             alpha: Some(1.0),
             color_dictionary: ColorDictionary::new(),
+            gamut_weights: None,
+            gamut_rotation: None,
+            near: None,
+            min_lightness_gap: None,
+            channel_jitter: None,
+            grayscale: false,
+            retry_policy: RetryPolicy::default(),
+            sampling: Sampling::default(),
+            candidate_filter: None,
+            mix: None,
+            algorithm_version: AlgorithmVersion::default(),
+            pick_strategy: None,
+            default_format: CssFormat::default(),
+            gamut_mapping: GamutMapping::default(),
+        }
+    }
+
+    /// Creates a new `RandomColor` instance for the given date, for "color of
+    /// the day" use cases.
+    ///
+    /// The generator is seeded from the date, and its hues are biased towards
+    /// the season the date falls in using the default `SeasonalWeights`.
+    ///
+    /// Parameters:
+    /// * `year`: The year of the date.
+    /// * `month`: The month of the date, in the range [1, 12].
+    /// * `day`: The day of the date, in the range [1, 31].
+    pub fn for_date(year: i32, month: u32, day: u32) -> RandomColor {
+        RandomColor::for_date_with(year, month, day, &SeasonalWeights::default())
+    }
+
+    /// Creates a new `RandomColor` instance for the given date, biasing its
+    /// hues with custom seasonal weights.
+    ///
+    /// Parameters:
+    /// * `year`: The year of the date.
+    /// * `month`: The month of the date, in the range [1, 12].
+    /// * `day`: The day of the date, in the range [1, 31].
+    /// * `weights`: The gamut weights to use for each season.
+    pub fn for_date_with(
+        year: i32,
+        month: u32,
+        day: u32,
+        weights: &SeasonalWeights,
+    ) -> RandomColor {
+        let mut random_color = RandomColor::new();
+
+        random_color
+            .seed(year as i64 * 10000 + month as i64 * 100 + day as i64)
+            .gamut_weights(weights.get(Season::from_month(month)));
+
+        random_color
+    }
+
+    /// Creates a new `RandomColor` instance for the current time bucket, so
+    /// that separate instances show the same "current color" without
+    /// coordinating, e.g. with a bucket of one hour or one day.
+    ///
+    /// Buckets are counted from the Unix epoch, in UTC.
+    ///
+    /// Parameters:
+    /// * `bucket`: The duration of each bucket.
+    pub fn for_time_bucket(bucket: Duration) -> RandomColor {
+        RandomColor::for_time_bucket_at(SystemTime::now(), bucket)
+    }
+
+    /// Creates a new `RandomColor` instance for the time bucket the given time
+    /// falls in.
+    ///
+    /// Parameters:
+    /// * `time`: The time to seed from.
+    /// * `bucket`: The duration of each bucket.
+    pub fn for_time_bucket_at(time: SystemTime, bucket: Duration) -> RandomColor {
+        let elapsed = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        let index = elapsed.as_millis() / bucket.as_millis().max(1);
+
+        let mut random_color = RandomColor::new();
+        random_color.seed(index as u64);
+
+        random_color
+    }
+
+    /// Generates a matched pair of colors for the added and removed lines of a
+    /// diff viewer, from the green and red families respectively.
+    ///
+    /// Both colors share the same saturation and brightness, and thus the
+    /// same HSL saturation and lightness, so neither stands out more than the
+    /// other. The same seed always yields the same pair.
+    ///
+    /// Parameters:
+    /// * `seed`: The seed of the pair, e.g. a session identifier.
+    pub fn diff_pair<T: Seed>(seed: T) -> (Color, Color) {
+        let mut generator = RandomColor::new();
+        generator.seed(seed).hue(Gamut::Green);
+
+        let (added_hue, s, b) = generator.generate_color();
+        let (removed_hue, _, _) = generator.hue(Gamut::Red).generate_color();
+
+        let added = generator.hsv_to_rgb(added_hue, s, b);
+        let removed = generator.hsv_to_rgb(removed_hue, s, b);
+
+        (
+            Color::new(added[0], added[1], added[2], 255),
+            Color::new(removed[0], removed[1], removed[2], 255),
+        )
+    }
+
+    /// Creates a new `RandomColor` instance generating variations of the given
+    /// hex color, seeded from the hex string itself so the variations are
+    /// reproducible.
+    ///
+    /// Parameters:
+    /// * `hex`: The reference color as a hex string.
+    /// * `tolerance`: The maximum deviation from the reference color for each
+    ///   HSV component.
+    pub fn seeded_near_hex(hex: &str, tolerance: i64) -> Result<RandomColor, HexParseError> {
+        let color = Color::from_hex(hex)?;
+        let mut random_color = RandomColor::new();
+
+        random_color.near(color, tolerance).seed(hex);
+
+        Ok(random_color)
+    }
+
+    /// Creates a new `RandomColor` instance that samples every color from `a`
+    /// with probability `weight`, and from `b` otherwise, e.g. for mostly
+    /// brand colors with occasional wild accents.
+    ///
+    /// Both generators share the random number generator of the new
+    /// instance, so seeding it makes the whole mix reproducible. The alpha
+    /// setting of the new instance applies to every color.
+    ///
+    /// Parameters:
+    /// * `a`: The generator sampled with probability `weight`.
+    /// * `b`: The generator sampled otherwise.
+    /// * `weight`: The probability of sampling `a`, clamped to the range
+    ///   [0.0, 1.0].
+    pub fn mix_of(a: RandomColor, b: RandomColor, weight: f32) -> RandomColor {
+        let mut random_color = RandomColor::new();
+
+        random_color.mix = Some(Box::new(Mix {
+            a,
+            b,
+            weight: weight.clamp(0.0, 1.0),
+        }));
+
+        random_color
+    }
+
+    /// Tries each generator in turn until one of them generates a color that
+    /// satisfies `predicate`, e.g. a minimum contrast or CIEDE2000 difference,
+    /// enabling layered aesthetic policies.
+    ///
+    /// Each generator tries as many candidates as its retry policy allows
+    /// before moving on to the next one. Returns `None` if no generator
+    /// satisfies the predicate.
+    ///
+    /// Parameters:
+    /// * `generators`: The generators to try, in order.
+    /// * `predicate`: The condition the color must satisfy.
+    pub fn first_satisfying<F: Fn(&Color) -> bool>(
+        generators: &mut [RandomColor],
+        predicate: F,
+    ) -> Option<Color> {
+        generators.iter_mut().find_map(|generator| {
+            (0..generator.retry_policy.max_attempts.max(1))
+                .map(|_| generator.to_color())
+                .find(|color| predicate(color))
+        })
+    }
+
+    /// Creates a new `RandomColor` instance from a preset registered with
+    /// `Presets::register`, if it exists.
+    ///
+    /// Parameters:
+    /// * `name`: The name of the preset.
+    pub fn from_preset(name: &str) -> Option<RandomColor> {
+        Presets::get(name)
+    }
+
+    /// Restores a generator and its color from a string produced by
+    /// `to_canonical_string`.
+    ///
+    /// The returned generator continues right after the stored color, so
+    /// generating from it yields the colors that would have followed.
+    ///
+    /// Parameters:
+    /// * `canonical`: The canonical string.
+    pub fn from_canonical_string(
+        canonical: &str,
+    ) -> Result<(RandomColor, Color), CanonicalParseError> {
+        let record = CanonicalRecord::parse(canonical)?;
+
+        let mut generator = record.generator;
+        generator.seed(record.seed);
+
+        let (h, s, b) = generator.generate_color();
+        if [h, s, b] != record.hsv {
+            return Err(CanonicalParseError::Mismatch);
         }
+
+        let [r, g, b] = generator.hsv_to_rgb(h, s, b);
+        let a = generator.pick_alpha();
+
+        Ok((generator, Color::new(r, g, b, a)))
     }
 
     /// Sets the hue setting.
@@ -104,12 +487,185 @@ impl RandomColor {
         self
     }
 
+    /// Sets the exact hue setting, pinning the hue to the given degrees, e.g.
+    /// a brand hue, while the saturation and brightness stay random.
+    ///
+    /// The pinned hue is used as is, even if a hue mask does not allow it.
+    pub fn exact_hue(&mut self, hue: i64) -> &mut RandomColor {
+        self.exact_hue = Some(hue.rem_euclid(360));
+
+        self
+    }
+
+    /// Sets the gamut weights setting, picking each hue from one of the given
+    /// gamuts with a probability proportional to its weight.
+    pub fn gamut_weights(&mut self, weights: &[(Gamut, u32)]) -> &mut RandomColor {
+        self.gamut_weights = Some(weights.to_vec());
+
+        self
+    }
+
+    /// Sets the gamut rotation setting, picking the hue of successive colors
+    /// from each of the given gamuts in turn, e.g. blue, green, purple, blue,
+    /// and so on, while the saturation and brightness stay random.
+    pub fn rotate_gamuts(&mut self, gamuts: &[Gamut]) -> &mut RandomColor {
+        self.gamut_rotation = Some(GamutRotation::new(gamuts));
+
+        self
+    }
+
+    /// Sets the gamut weights setting to pick hues from several gamuts, e.g.
+    /// `.hues(&[Gamut::Blue, Gamut::Purple, Gamut::Pink])`, as if their hue
+    /// ranges were a single one.
+    ///
+    /// Each gamut is weighted by the width of its hue range in the current
+    /// color dictionary, so a custom dictionary must be set beforehand.
+    /// Monochrome has a single hue and the smallest weight.
+    pub fn hues(&mut self, gamuts: &[Gamut]) -> &mut RandomColor {
+        let weights: Vec<(Gamut, u32)> = gamuts
+            .iter()
+            .map(|gamut| {
+                let [min, max] = self.color_dictionary.get_color_from_gamut(gamut).range;
+
+                (*gamut, (max - min).max(1) as u32)
+            })
+            .collect();
+
+        self.gamut_weights(&weights)
+    }
+
+    /// Sets the gamut weights setting from how often each gamut is already
+    /// in use, e.g. on screen, biasing generation away from the most used
+    /// ones to keep long-running dashboards visually balanced.
+    ///
+    /// Every gamut but monochrome is weighted, unless monochrome is listed in
+    /// `counts`. Each weight is inversely proportional to the count plus one,
+    /// so unused gamuts are the most likely.
+    ///
+    /// Parameters:
+    /// * `counts`: How many times each gamut is in use. Unlisted gamuts count
+    ///   as unused.
+    pub fn weights_from_counts(&mut self, counts: &[(Gamut, usize)]) -> &mut RandomColor {
+        let count_of = |gamut: Gamut| {
+            counts
+                .iter()
+                .filter(|(g, _)| *g == gamut)
+                .map(|(_, count)| *count)
+                .sum::<usize>()
+        };
+
+        let mut gamuts = vec![
+            Gamut::Red,
+            Gamut::Orange,
+            Gamut::Yellow,
+            Gamut::Green,
+            Gamut::Blue,
+            Gamut::Purple,
+            Gamut::Pink,
+        ];
+
+        if counts.iter().any(|(g, _)| *g == Gamut::Monochrome) {
+            gamuts.insert(0, Gamut::Monochrome);
+        }
+
+        let weights: Vec<(Gamut, u32)> = gamuts
+            .into_iter()
+            .map(|gamut| (gamut, (1000 / (count_of(gamut) + 1)).max(1) as u32))
+            .collect();
+
+        self.gamut_weights(&weights)
+    }
+
     /// Removes the luminosity setting.
     pub fn luminosity(&mut self, luminosity: Luminosity) -> &mut RandomColor {
         self.luminosity = Some(luminosity);
         self
     }
 
+    /// Sets a blend between two luminosities, interpolating their saturation
+    /// and brightness windows, for gradual aesthetic dials instead of
+    /// discrete jumps.
+    ///
+    /// Parameters:
+    /// * `from`: The luminosity used when `t` is 0.0.
+    /// * `to`: The luminosity used when `t` is 1.0.
+    /// * `t`: The position of the blend, clamped to the range [0.0, 1.0].
+    pub fn luminosity_blend(
+        &mut self,
+        from: Luminosity,
+        to: Luminosity,
+        t: f32,
+    ) -> &mut RandomColor {
+        self.luminosity_blend = Some(LuminosityBlend {
+            from,
+            to,
+            t: t.clamp(0.0, 1.0),
+        });
+
+        self
+    }
+
+    /// Sets an aesthetic style, such as pastel or neon, picking the
+    /// saturation and brightness from the window of the style instead of the
+    /// luminosity.
+    ///
+    /// Parameters:
+    /// * `style`: The style of the colors.
+    pub fn style(&mut self, style: Style) -> &mut RandomColor {
+        self.style = Some(style);
+
+        self
+    }
+
+    /// Sets the hue mask setting, allowing only the hue degrees set to `true`,
+    /// e.g. from a map generated by an external tool.
+    ///
+    /// The mask intersects with the other hue settings: hues are picked among
+    /// the allowed degrees of the range they select, or among every allowed
+    /// degree if that range has none. A mask with no allowed degree is
+    /// ignored.
+    pub fn hue_mask(&mut self, mask: &[bool; 360]) -> &mut RandomColor {
+        self.hue_mask = Some(Box::new(*mask));
+
+        self
+    }
+
+    /// Sets the saturation setting, to a single value or an inclusive range
+    /// of values in the range [0, 100], e.g. `.saturation(10..=25)` for very
+    /// muted colors. It overrides the saturation range of the hue.
+    pub fn saturation<R: Into<ValueRange>>(&mut self, saturation: R) -> &mut RandomColor {
+        self.saturation = Some(saturation.into());
+
+        self
+    }
+
+    /// Sets the brightness setting, to a single value or an inclusive range
+    /// of values in the range [0, 100], e.g. `.brightness(80..=100)`. It
+    /// overrides the brightness range of the hue and saturation.
+    pub fn brightness<R: Into<ValueRange>>(&mut self, brightness: R) -> &mut RandomColor {
+        self.brightness = Some(brightness.into());
+
+        self
+    }
+
+    /// Sets the saturation bias setting, reshaping how saturation values are
+    /// sampled within their range without changing its bounds, e.g.
+    /// `Curve::EaseIn` for mostly muted colors.
+    pub fn saturation_bias(&mut self, curve: Curve) -> &mut RandomColor {
+        self.saturation_bias = Some(curve);
+
+        self
+    }
+
+    /// Sets the value bias setting, reshaping how brightness values are
+    /// sampled within their range without changing its bounds, e.g.
+    /// `Curve::EaseOut` for mostly bright colors.
+    pub fn value_bias(&mut self, curve: Curve) -> &mut RandomColor {
+        self.value_bias = Some(curve);
+
+        self
+    }
+
     /// Sets the seed.
     pub fn seed<T: Seed>(&mut self, seed: T) -> &mut RandomColor {
         self.seed = SmallRng::seed_from_u64(seed.to_value());
@@ -117,15 +673,62 @@ impl RandomColor {
         self
     }
 
-    /// Sets the alpha setting.
+    /// Sets the seed from several seeds combined, e.g. the IDs of the members
+    /// of a group, so they share a deterministic color.
+    ///
+    /// The order of the seeds does not matter, but duplicates do.
+    pub fn seed_combined<T: Seed + Clone>(&mut self, seeds: &[T]) -> &mut RandomColor {
+        let mut values: Vec<u64> = seeds.iter().map(|seed| seed.clone().to_value()).collect();
+        values.sort_unstable();
+
+        let combined = values.into_iter().fold(seeds.len() as u64, |state, value| {
+            SmallRng::seed_from_u64(state ^ value).gen()
+        });
+
+        self.seed(combined)
+    }
+
+    /// Domain-separates the seed with a namespace, so the same seed yields
+    /// different but stable colors in different parts of an application.
+    ///
+    /// The namespace is mixed into the current state of the generator, so it
+    /// must be set after the seed, e.g. `.seed(user_id).namespace("chat")`.
+    pub fn namespace(&mut self, namespace: &str) -> &mut RandomColor {
+        let state: u64 = self.seed.gen();
+
+        self.seed = SmallRng::seed_from_u64(options::hash_str(namespace) ^ state);
+
+        self
+    }
+
+    /// Sets the alpha setting, clamped to the range [0.0, 1.0].
+    ///
+    /// A NaN alpha is ignored. Use `try_alpha` to reject invalid values
+    /// instead.
     pub fn alpha(&mut self, alpha: f32) -> &mut RandomColor {
-        if alpha < 1.0 {
-            self.alpha = Some(alpha);
+        if !alpha.is_nan() {
+            self.alpha = Some(alpha.clamp(0.0, 1.0));
         }
 
         self
     }
 
+    /// Sets the alpha setting, or returns an error if it is NaN or outside
+    /// the range [0.0, 1.0], leaving the setting unchanged.
+    pub fn try_alpha(&mut self, alpha: f32) -> Result<&mut RandomColor, AlphaError> {
+        if alpha.is_nan() {
+            return Err(AlphaError::NotANumber);
+        }
+
+        if !(0.0..=1.0).contains(&alpha) {
+            return Err(AlphaError::OutOfRange(alpha));
+        }
+
+        self.alpha = Some(alpha);
+
+        Ok(self)
+    }
+
     /// Removes the alpha setting.
     pub fn random_alpha(&mut self) -> &mut RandomColor {
         self.alpha = None;
@@ -133,6 +736,124 @@ impl RandomColor {
         self
     }
 
+    /// Sets the neighborhood setting, restricting generation to colors close
+    /// to the given one.
+    pub fn near(&mut self, color: Color, tolerance: i64) -> &mut RandomColor {
+        let [h, s, b] = color.to_hsv_array();
+
+        self.near = Some(Near {
+            hue: h as i64,
+            saturation: s as i64,
+            brightness: b as i64,
+            tolerance: tolerance.abs(),
+        });
+
+        self
+    }
+
+    /// Sets the minimum lightness gap setting, so every pair of colors in a
+    /// palette differs by at least `delta` in CIE L* lightness and remains
+    /// readable when printed in black and white.
+    ///
+    /// Lightness spans [0, 100], so at most `100 / delta + 1` colors can
+    /// satisfy the constraint. The gap is best-effort: when no color within
+    /// the retry policy's attempts satisfies it, e.g. because the palette is
+    /// too large or the other settings are too narrow, the policy's fallback
+    /// is used instead, so some pairs may end up closer than `delta`.
+    pub fn min_lightness_gap(&mut self, delta: f32) -> &mut RandomColor {
+        self.min_lightness_gap = Some(delta);
+
+        self
+    }
+
+    /// Sets the maximum random offset applied to each RGB channel by `jitter`.
+    ///
+    /// Parameters:
+    /// * `r`: The maximum offset of the red channel.
+    /// * `g`: The maximum offset of the green channel.
+    /// * `b`: The maximum offset of the blue channel.
+    pub fn channel_jitter(&mut self, r: u8, g: u8, b: u8) -> &mut RandomColor {
+        self.channel_jitter = Some([r, g, b]);
+
+        self
+    }
+
+    /// Forces every generated color to be a gray with exactly equal RGB
+    /// channels, keeping the brightness of the picked color.
+    pub fn force_grayscale(&mut self) -> &mut RandomColor {
+        self.grayscale = true;
+
+        self
+    }
+
+    /// Sets the RetryPolicy.
+    pub fn retry_policy(&mut self, policy: RetryPolicy) -> &mut RandomColor {
+        self.retry_policy = policy;
+
+        self
+    }
+
+    /// Sets the Sampling mode.
+    ///
+    /// With `Sampling::Edge`, the brightness is picked just above the
+    /// dictionary's lower bounds for the picked hue and saturation, yielding
+    /// the most saturated representable colors. It takes precedence over the
+    /// brightness chosen by `luminosity`, but not over `near`.
+    pub fn sampling(&mut self, sampling: Sampling) -> &mut RandomColor {
+        self.sampling = sampling;
+
+        self
+    }
+
+    /// Sets a custom rule applied to every HSV candidate inside the
+    /// generation loop, e.g. to keep away from a competitor's brand hue.
+    ///
+    /// Rejected candidates are regenerated following the retry policy; when
+    /// it is exhausted, its fallback is used, with `BestCandidate` behaving
+    /// like `LastCandidate`.
+    pub fn on_candidate<F>(&mut self, filter: F) -> &mut RandomColor
+    where
+        F: Fn([i64; 3]) -> Candidate + Send + Sync + 'static,
+    {
+        self.candidate_filter = Some(CandidateFilter::new(filter));
+
+        self
+    }
+
+    /// Sets the PickStrategy, replacing the default heuristics.
+    pub fn pick_strategy<S: PickStrategy + 'static>(&mut self, strategy: S) -> &mut RandomColor {
+        self.pick_strategy = Some(SharedPickStrategy::new(strategy));
+
+        self
+    }
+
+    /// Sets the AlgorithmVersion.
+    ///
+    /// `AlgorithmVersion::V2` converts hues 0 and 360 exactly, rounds the
+    /// 8-bit RGB channels instead of flooring them and keeps the `f32` RGB
+    /// channels unquantized, at the cost of changing the colors existing
+    /// seeds produce.
+    pub fn algorithm_version(&mut self, version: AlgorithmVersion) -> &mut RandomColor {
+        self.algorithm_version = version;
+
+        self
+    }
+
+    /// Sets the CSS syntax used when displaying the generator with `{}`.
+    pub fn default_format(&mut self, format: CssFormat) -> &mut RandomColor {
+        self.default_format = format;
+
+        self
+    }
+
+    /// Sets how colors computed outside the sRGB gamut are brought into it
+    /// before being output as 8-bit channels, e.g. by `to_colormap`.
+    pub fn gamut_mapping(&mut self, mapping: GamutMapping) -> &mut RandomColor {
+        self.gamut_mapping = mapping;
+
+        self
+    }
+
     /// Sets the ColorDictionary.
     pub fn dictionary(&mut self, dictionary: ColorDictionary) -> &mut RandomColor {
         self.color_dictionary = dictionary;
@@ -147,6 +868,15 @@ impl RandomColor {
         [h as u32, s as u32, b as u32]
     }
 
+    /// Generates a random color and returns it as an `f32` HSV array, with
+    /// the hue in the range [0.0, 360.0) and the saturation and brightness
+    /// in the range [0.0, 1.0], ready for further HSV math.
+    pub fn to_hsv_f32_array(&mut self) -> [f32; 3] {
+        let (h, s, b) = self.generate_color();
+
+        [h.rem_euclid(360) as f32, s as f32 / 100.0, b as f32 / 100.0]
+    }
+
     /// Generates a random color and returns it as an RGB string.
     pub fn to_rgb_string(&mut self) -> String {
         let (h, s, b) = self.generate_color();
@@ -167,6 +897,15 @@ impl RandomColor {
         format!("rgba({}, {}, {}, {})", rgb[0], rgb[1], rgb[2], a)
     }
 
+    /// Generates a random color and returns it as an RGB string in the
+    /// space-separated CSS Color 4 syntax, e.g. `rgb(174 236 249 / 0.5)`.
+    pub fn to_modern_rgb_string(&mut self) -> String {
+        let [r, g, b, a] = self.to_f32_rgba_array();
+        let [r, g, b] = [r, g, b].map(|c| (c * 255.0).round() as u8);
+
+        format!("rgb({} {} {} / {})", r, g, b, a)
+    }
+
     /// Generates a random color and returns it as an RGB array.
     pub fn to_rgb_array(&mut self) -> [u8; 3] {
         let (h, s, b) = self.generate_color();
@@ -179,569 +918,4377 @@ impl RandomColor {
         let (h, s, b) = self.generate_color();
         let rgb: [u8; 3] = self.hsv_to_rgb(h, s, b);
 
-        let alpha = match self.alpha {
-            Some(alpha) => (alpha * 255.0) as u8,
-            None => self.random_within(0, 255) as u8,
-        };
+        let alpha = self.pick_alpha();
 
         [rgb[0], rgb[1], rgb[2], alpha]
     }
 
     /// Generates a random color and returns it as a `f32` RGB array.
+    ///
+    /// With `AlgorithmVersion::V2`, the channels are converted straight from
+    /// HSV without quantizing them to 8 bits.
     pub fn to_f32_rgb_array(&mut self) -> [f32; 3] {
         let (h, s, b) = self.generate_color();
-        let rgb: [u8; 3] = self.hsv_to_rgb(h, s, b);
 
-        [
-            rgb[0] as f32 / 255.0,
-            rgb[1] as f32 / 255.0,
-            rgb[2] as f32 / 255.0,
-        ]
+        self.hsv_to_f32_rgb(h, s, b)
+    }
+
+    /// Generates a random color and returns it as a 16-bit RGB array, with
+    /// every channel in the range [0, 65535].
+    ///
+    /// The channels are converted straight from HSV rather than scaled from
+    /// the 8-bit ones, so they keep the extra precision.
+    pub fn to_rgb16_array(&mut self) -> [u16; 3] {
+        self.generate_exact_rgb()
+            .map(|c| (c * 65535.0).round() as u16)
     }
 
     /// Generates a random color and returns it as an `f32` RGBA array.
+    ///
+    /// With `AlgorithmVersion::V2`, the channels are converted straight from
+    /// HSV without quantizing them to 8 bits.
     pub fn to_f32_rgba_array(&mut self) -> [f32; 4] {
         let (h, s, b) = self.generate_color();
-        let rgb: [u8; 3] = self.hsv_to_rgb(h, s, b);
+        let rgb = self.hsv_to_f32_rgb(h, s, b);
 
         let alpha: f32 = match self.alpha {
             Some(alpha) => alpha,
             None => self.seed.gen_range(0.0..1.0),
         };
 
-        [
-            rgb[0] as f32 / 255.0,
-            rgb[1] as f32 / 255.0,
-            rgb[2] as f32 / 255.0,
-            alpha,
-        ]
+        [rgb[0], rgb[1], rgb[2], alpha]
+    }
+
+    /// Generates a random color and returns it as an RGBA array, with the RGB
+    /// channels premultiplied by the alpha channel.
+    ///
+    /// The other RGBA outputs use straight alpha, except for the `ecolor`
+    /// conversions: `Color32` premultiplies in linear light, so its channels
+    /// differ slightly from this output for translucent colors.
+    pub fn to_premultiplied_rgba_array(&mut self) -> [u8; 4] {
+        let [r, g, b, a] = self.to_rgba_array();
+        let premultiply = |c: u8| ((c as u16 * a as u16 + 127) / 255) as u8;
+
+        [premultiply(r), premultiply(g), premultiply(b), a]
+    }
+
+    /// Generates a random color and returns it as an `f32` RGBA array, with
+    /// the RGB channels premultiplied by the alpha channel.
+    ///
+    /// This matches the conversion into the `ecolor` crate's `Rgba`, which
+    /// premultiplies the same channels.
+    pub fn to_premultiplied_f32_rgba_array(&mut self) -> [f32; 4] {
+        let [r, g, b, a] = self.to_f32_rgba_array();
+
+        [r * a, g * a, b * a, a]
+    }
+
+    /// Generates a random color and returns it as a linear `f32` RGB array,
+    /// with the sRGB transfer function removed, as expected by shaders.
+    pub fn to_linear_rgb_array(&mut self) -> [f32; 3] {
+        self.to_f32_rgb_array().map(conversions::srgb_to_linear)
+    }
+
+    /// Generates a random color and returns it as a linear `f32` RGBA array,
+    /// with the sRGB transfer function removed from the RGB channels. The
+    /// alpha channel is already linear and is kept as is.
+    pub fn to_linear_rgba_array(&mut self) -> [f32; 4] {
+        let [r, g, b, a] = self.to_f32_rgba_array();
+        let [r, g, b] = [r, g, b].map(conversions::srgb_to_linear);
+
+        [r, g, b, a]
     }
 
-    /// Generates a random color and returns it as an HSL string.
+    /// Generates a random color and returns it as an HSL string, with the
+    /// saturation and lightness rounded to one decimal.
     pub fn to_hsl_string(&mut self) -> String {
-        let (h, s, b) = self.generate_color();
-        let hsv = self.hsv_to_hsl(h, s, b);
+        let [h, s, l] = self.to_hsl_f32_array();
 
-        format!("hsl({}, {}%, {}%)", hsv[0], hsv[1], hsv[2])
+        format!("hsl({}, {:.1}%, {:.1}%)", h, s, l)
     }
 
-    /// Generates a random color and returns it as an HSLA string.
+    /// Generates a random color and returns it as an HSLA string, with the
+    /// saturation and lightness rounded to one decimal.
     pub fn to_hsla_string(&mut self) -> String {
-        let (h, s, b) = self.generate_color();
-        let hsv = self.hsv_to_hsl(h, s, b);
+        let [h, s, l] = self.to_hsl_f32_array();
         let a: f32 = match self.alpha {
             Some(alpha) => alpha,
             None => rand::random(),
         };
 
-        format!("hsl({}, {}%, {}%, {})", hsv[0], hsv[1], hsv[2], a)
+        format!("hsl({}, {:.1}%, {:.1}%, {})", h, s, l, a)
+    }
+
+    /// Generates a random color and returns it as an HSL string in the
+    /// space-separated CSS Color 4 syntax, e.g. `hsl(191 88.0% 83.3% / 0.5)`.
+    pub fn to_modern_hsl_string(&mut self) -> String {
+        let [h, s, l] = self.to_hsl_f32_array();
+        let a: f32 = match self.alpha {
+            Some(alpha) => alpha,
+            None => self.seed.gen_range(0.0..1.0),
+        };
+
+        format!("hsl({} {:.1}% {:.1}% / {})", h, s, l, a)
     }
 
     /// Generates a random color and returns it as an HSL array.
+    ///
+    /// The components are truncated to integers; `to_hsl_f32_array` returns
+    /// the exact conversion.
     pub fn to_hsl_array(&mut self) -> [u32; 3] {
         let (h, s, b) = self.generate_color();
 
         self.hsv_to_hsl(h, s, b)
     }
 
-    /// Generates a random color and returns it as a hex string.
-    pub fn to_hex(&mut self) -> String {
+    /// Generates a random color and returns it as an `f32` HSL array, with
+    /// the hue in the range [0.0, 360.0) and the saturation and lightness in
+    /// the range [0.0, 100.0].
+    pub fn to_hsl_f32_array(&mut self) -> [f32; 3] {
         let (h, s, b) = self.generate_color();
-        let [r, g, b] = self.hsv_to_rgb(h, s, b);
+        let [h, s, l] =
+            conversions::hsv_to_hsl([h.rem_euclid(360) as f32, s as f32 / 100.0, b as f32 / 100.0]);
 
-        format!("#{:02x}{:02x}{:02x}", r, g, b)
+        [h, s * 100.0, l * 100.0]
     }
 
-    /// Transforms the `RandomColor` into a `u8` array with the color's RGB values.
-    pub fn into_rgb_array(self) -> [u8; 3] {
-        self.clone().to_rgb_array()
+    /// Generates a random color and returns it as an HWB array, with the hue
+    /// in the range [0, 360) and the whiteness and blackness in the range
+    /// [0, 100].
+    pub fn to_hwb_array(&mut self) -> [u32; 3] {
+        let (h, s, b) = self.generate_color();
+
+        self.hsv_to_hwb(h, s, b)
     }
 
-    /// Transforms the `RandomColor` into a `u8` array with the color's RGBA values.
-    pub fn into_rgba_array(self) -> [u8; 4] {
-        self.clone().to_rgba_array()
+    /// Generates a random color and returns it as a CSS `hwb()` string.
+    pub fn to_hwb_string(&mut self) -> String {
+        let [h, w, b] = self.to_hwb_array();
+
+        format!("hwb({} {}% {}%)", h, w, b)
     }
 
-    /// Transforms the `RandomColor` into a `f32` array with the color's RGB values.
-    pub fn into_f32_rgb_array(self) -> [f32; 3] {
-        self.clone().to_f32_rgb_array()
+    /// Generates a random color and returns it as a CMYK array, with every
+    /// component in the range [0.0, 1.0].
+    pub fn to_cmyk_array(&mut self) -> [f32; 4] {
+        let rgb = self.to_f32_rgb_array();
+
+        self.rgb_to_cmyk(rgb)
     }
 
-    /// Transforms the `RandomColor` into a `f32` array with the color's RGBA values.
-    pub fn into_f32_rgba_array(self) -> [f32; 4] {
-        self.clone().to_f32_rgba_array()
+    /// Generates a random color and returns it as a CMYK string.
+    pub fn to_cmyk_string(&mut self) -> String {
+        let [c, m, y, k] = self.to_cmyk_array().map(|v| (v * 100.0).round());
+
+        format!("cmyk({}%, {}%, {}%, {}%)", c, m, y, k)
     }
 
-    /// Generates a random color based on the settings.
-    fn generate_color(&mut self) -> (i64, i64, i64) {
-        let h = self.pick_hue();
-        let s = self.pick_saturation(&h);
-        let b = self.pick_brightness(&h, &s);
+    /// Generates a random color and returns it as an `f32` CIE XYZ array,
+    /// relative to the D65 white point, with Y in the range [0.0, 1.0].
+    ///
+    /// The color is converted straight from HSV, without quantizing it to
+    /// 8-bit RGB first.
+    pub fn to_xyz_array(&mut self) -> [f32; 3] {
+        let rgb = self.generate_exact_rgb();
 
-        (h, s, b)
+        conversions::rgb_to_xyz(rgb)
     }
 
-    /// Picks a random hue based on the hue setting.
-    fn pick_hue(&mut self) -> i64 {
-        match self.hue {
-            None => self.random_within(0, 361),
-            Some(ref gamut) => {
-                let color = self.color_dictionary.get_color_from_gamut(gamut);
-                self.random_within(color.range[0], color.range[1])
-            }
+    /// Generates a random color and returns it as an `f32` CIE XYZ array,
+    /// adapted to the D50 white point with the Bradford transform, as used by
+    /// ICC profiles in print pipelines.
+    pub fn to_xyz_d50_array(&mut self) -> [f32; 3] {
+        let xyz = self.to_xyz_array();
+
+        conversions::adapt_white_point(xyz, conversions::D65_WHITE, conversions::D50_WHITE)
+    }
+
+    /// Generates a random color and returns it as a CIE L*a*b* array, relative
+    /// to the D65 white point and rounded to the nearest integer.
+    pub fn to_lab_array(&mut self) -> [i32; 3] {
+        self.to_f32_lab_array().map(|v| v.round() as i32)
+    }
+
+    /// Generates a random color and returns it as an `f32` CIE L*a*b* array,
+    /// relative to the D65 white point.
+    ///
+    /// The color is converted straight from HSV, without quantizing it to
+    /// 8-bit RGB first.
+    pub fn to_f32_lab_array(&mut self) -> [f32; 3] {
+        let rgb = self.generate_exact_rgb();
+
+        conversions::rgb_to_lab(rgb)
+    }
+
+    /// Generates a random color and returns it as an `f32` CIE L*a*b* array,
+    /// relative to the D50 white point after Bradford adaptation, as used by
+    /// ICC profiles in print pipelines.
+    pub fn to_lab_d50_array(&mut self) -> [f32; 3] {
+        let xyz = self.to_xyz_d50_array();
+
+        conversions::xyz_to_lab(xyz, conversions::D50_WHITE)
+    }
+
+    /// Generates a random color and returns it as a CIE LCH(ab) array,
+    /// relative to the D65 white point, with the hue in degrees.
+    ///
+    /// The color is converted straight from HSV, without quantizing it to
+    /// 8-bit RGB first.
+    pub fn to_lch_array(&mut self) -> [f32; 3] {
+        let lab = self.to_f32_lab_array();
+
+        conversions::lab_to_lch(lab)
+    }
+
+    /// Generates a random color and returns it as a CSS `lch()` string.
+    pub fn to_lch_string(&mut self) -> String {
+        let [l, c, h] = self.to_lch_array();
+
+        format!("lch({:.2}% {:.2} {:.2})", l, c, h)
+    }
+
+    /// Generates a random color and returns it in an approximate Munsell
+    /// notation, e.g. `10BG 9.0/4`, with the hue in steps of 2.5, or `N 5.0/`
+    /// for neutral colors.
+    ///
+    /// The notation is derived from CIE LCH(ab) rather than the Munsell
+    /// renotation data, so it is only meant as a rough guide.
+    pub fn to_munsell_string(&mut self) -> String {
+        const FAMILIES: [&str; 10] = ["R", "YR", "Y", "GY", "G", "BG", "B", "PB", "P", "RP"];
+
+        let [hue, value, chroma] = conversions::lch_to_munsell(self.to_lch_array());
+
+        if chroma < 0.5 {
+            return format!("N {:.1}/", value);
         }
+
+        let steps = match (hue / 2.5).round() as usize % 40 {
+            0 => 40,
+            steps => steps,
+        };
+        let family = (steps - 1) / 4;
+        let step = (steps - family * 4) as f32 * 2.5;
+
+        format!("{}{} {:.1}/{:.0}", step, FAMILIES[family], value, chroma)
+    }
+
+    /// Generates a random color and returns it as an HSLuv array, with the hue
+    /// in degrees and the saturation and lightness in the range [0.0, 100.0].
+    ///
+    /// Unlike HSL, the HSLuv lightness is perceptually uniform. The color is
+    /// converted straight from HSV, without quantizing it to 8-bit RGB first.
+    pub fn to_hsluv_array(&mut self) -> [f32; 3] {
+        let rgb = self.generate_exact_rgb();
+
+        conversions::rgb_to_hsluv(rgb)
+    }
+
+    /// Generates a random color and returns it as an OKLCH array, with the
+    /// lightness in the range [0.0, 1.0] and the hue in degrees.
+    ///
+    /// The color is converted straight from HSV, without quantizing it to
+    /// 8-bit RGB first.
+    pub fn to_oklch_array(&mut self) -> [f32; 3] {
+        let rgb = self.generate_exact_rgb();
+
+        conversions::lab_to_lch(conversions::rgb_to_oklab(rgb))
+    }
+
+    /// Generates a random color and returns it as a CSS `oklch()` string.
+    pub fn to_oklch_string(&mut self) -> String {
+        let [l, c, h] = self.to_oklch_array();
+
+        format!("oklch({:.2}% {:.4} {:.2})", l * 100.0, c, h)
+    }
+
+    /// Generates a random color and returns it as a CSS `color()` string in
+    /// the Display P3 space, for wide-gamut displays.
+    ///
+    /// The color is converted straight from HSV, without quantizing it to
+    /// 8-bit RGB first.
+    pub fn to_display_p3_string(&mut self) -> String {
+        let rgb = self.generate_exact_rgb();
+        let a: f32 = match self.alpha {
+            Some(alpha) => alpha,
+            None => self.seed.gen_range(0.0..1.0),
+        };
+        let [r, g, b] = conversions::rgb_to_display_p3(rgb);
+
+        format!("color(display-p3 {:.4} {:.4} {:.4} / {})", r, g, b, a)
+    }
+
+    /// Generates a random color and returns it as an `f32` Rec.2020 array,
+    /// encoded with the BT.2020 transfer function, for HDR and video
+    /// compositing.
+    ///
+    /// The color is converted straight from HSV, without quantizing it to
+    /// 8-bit RGB first.
+    pub fn to_rec2020_array(&mut self) -> [f32; 3] {
+        let rgb = self.generate_exact_rgb();
+
+        conversions::rgb_to_rec2020(rgb)
+    }
+
+    /// Generates a random color and returns it as a hex string.
+    pub fn to_hex(&mut self) -> String {
+        let (h, s, b) = self.generate_color();
+        let [r, g, b] = self.hsv_to_rgb(h, s, b);
+
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
     }
 
-    /// Picks a random saturation value based on the hue and luminosity setting.
+    /// Generates a random color and returns it as a 3-digit hex string, e.g.
+    /// `#aef`, for compact inline styles.
+    ///
+    /// Returns `None` if the color is not exactly representable with 3 digits,
+    /// unless `snap` is set, in which case every channel is snapped to the
+    /// nearest representable value instead.
     ///
     /// Parameters:
-    /// * `hue`: The hue of the color.
-    fn pick_saturation(&mut self, hue: &i64) -> i64 {
-        let s_range: (i64, i64) = self.color_dictionary.get_saturation_range(hue);
+    /// * `snap`: Whether to snap the color to the nearest representable one.
+    pub fn to_short_hex(&mut self, snap: bool) -> Option<String> {
+        let rgb = self.to_rgb_array();
 
-        let s_min = s_range.0;
-        let s_max = s_range.1;
+        if !snap && rgb.iter().any(|c| c % 17 != 0) {
+            return None;
+        }
+
+        let [r, g, b] = rgb.map(|c| (c as f32 / 17.0).round() as u8);
+
+        Some(format!("#{:x}{:x}{:x}", r, g, b))
+    }
+
+    /// Generates a random color and returns it as a hex string snapped to the
+    /// 216-color web-safe palette, where every channel is a multiple of
+    /// `0x33`, for clients with very limited color support.
+    pub fn to_web_safe_hex(&mut self) -> String {
+        let [r, g, b] = self
+            .to_rgb_array()
+            .map(|c| (c as f32 / 51.0).round() as u8 * 51);
+
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+
+    /// Generates a random color and returns it as a hex string with the alpha
+    /// channel appended, in the `#rrggbbaa` form.
+    pub fn to_hex_with_alpha(&mut self) -> String {
+        let [r, g, b, a] = self.to_rgba_array();
+
+        format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a)
+    }
 
-        match self.luminosity {
-            Some(Luminosity::Random) => self.random_within(0, 100),
-            Some(Luminosity::Bright) => self.random_within(55, s_max),
-            Some(Luminosity::Dark) => self.random_within(s_max - 10, s_max),
-            Some(Luminosity::Light) => self.random_within(s_min, 55),
-            _ => self.random_within(s_min, s_max),
+    /// Generates a random color and returns it as a CSS string in the given
+    /// syntax, e.g. one picked by the user.
+    ///
+    /// Parameters:
+    /// * `format`: The CSS color syntax.
+    pub fn to_css(&mut self, format: CssFormat) -> String {
+        match format {
+            CssFormat::Hex => self.to_hex(),
+            CssFormat::HexWithAlpha => self.to_hex_with_alpha(),
+            CssFormat::Rgb => self.to_rgb_string(),
+            CssFormat::Rgba => self.to_rgba_string(),
+            CssFormat::ModernRgb => self.to_modern_rgb_string(),
+            CssFormat::Hsl => self.to_hsl_string(),
+            CssFormat::Hsla => self.to_hsla_string(),
+            CssFormat::ModernHsl => self.to_modern_hsl_string(),
+            CssFormat::Hwb => self.to_hwb_string(),
+            CssFormat::Lch => self.to_lch_string(),
+            CssFormat::Oklch => self.to_oklch_string(),
+            CssFormat::DisplayP3 => self.to_display_p3_string(),
         }
     }
 
-    /// Picks a random brightness value based on the hue and saturation, as well
-    /// as the luminosity setting.
+    /// Fills a buffer with tightly packed random RGBA colors, one per four
+    /// bytes, and returns the number of colors written.
+    ///
+    /// Trailing bytes that do not make up a whole color are left untouched.
     ///
     /// Parameters:
-    /// * `hue`: The hue of the color.
-    /// * `saturation`: The saturation of the color.
-    fn pick_brightness(&mut self, hue: &i64, saturation: &i64) -> i64 {
-        let b_min = self.color_dictionary.get_minimum_value(hue, saturation);
-        let b_max = 100;
+    /// * `buffer`: The buffer to fill.
+    pub fn fill_rgba(&mut self, buffer: &mut [u8]) -> usize {
+        let mut count = 0;
 
-        match self.luminosity {
-            Some(Luminosity::Random) => self.random_within(0, 100),
-            Some(Luminosity::Light) => self.random_within((b_max + b_min) / 2, b_max),
-            Some(Luminosity::Dark) => self.random_within(b_min, b_min + 20),
-            _ => self.random_within(b_min, b_max),
+        for chunk in buffer.chunks_exact_mut(4) {
+            chunk.copy_from_slice(&self.to_rgba_array());
+            count += 1;
         }
+
+        count
     }
 
-    /// Generates a random i64 within the given range.
+    /// Fills a buffer with tightly packed random `f32` RGBA colors, one per
+    /// four values, and returns the number of colors written.
     ///
-    /// This function first ensures that `min` is less than or equal to `max`.
-    /// If `min` is equal to `max`, it increments `max` by 1 to ensure that the
-    /// range is not empty. It uses the `SmallRng` in the seed property to
-    /// generate the random number.
+    /// Trailing values that do not make up a whole color are left untouched.
     ///
     /// Parameters:
-    /// * `min`: The minimum value of the range.
-    /// * `max`: The maximum value of the range.
-    fn random_within(&mut self, mut min: i64, mut max: i64) -> i64 {
-        if min > max {
-            std::mem::swap(&mut min, &mut max);
-        }
+    /// * `buffer`: The buffer to fill.
+    pub fn fill_f32_rgba(&mut self, buffer: &mut [f32]) -> usize {
+        let mut count = 0;
 
-        if min == max {
-            max += 1;
+        for chunk in buffer.chunks_exact_mut(4) {
+            chunk.copy_from_slice(&self.to_f32_rgba_array());
+            count += 1;
         }
 
-        self.seed.gen_range(min..max)
+        count
     }
 
-    /// Convert a color from HSV to RGB.
+    /// Generates a random `f32` RGBA color for each of `count` vertices.
     ///
     /// Parameters:
-    /// * `hue`: The hue of the color in the range [0, 360).
-    /// * `saturation`: The saturation of the color in the range [0, 100].
-    /// * `brightness`: The brightness of the color in the range [0, 100].
-    fn hsv_to_rgb(&self, mut hue: i64, saturation: i64, brightness: i64) -> [u8; 3] {
-        if hue == 0 {
-            hue = 1;
+    /// * `count`: The number of vertices.
+    /// * `layout`: The memory layout of the returned data.
+    pub fn assign_vertex_colors(&mut self, count: usize, layout: VertexLayout) -> Vec<f32> {
+        self.assign_flat_face_colors(count, 1, layout)
+    }
+
+    /// Generates a random `f32` RGBA color for each face of a mesh and
+    /// repeats it for every vertex of the face, for flat shading.
+    ///
+    /// Parameters:
+    /// * `count`: The number of vertices.
+    /// * `vertices_per_face`: The number of consecutive vertices sharing a
+    ///   color, e.g. 3 for a triangle list.
+    /// * `layout`: The memory layout of the returned data.
+    pub fn assign_flat_face_colors(
+        &mut self,
+        count: usize,
+        vertices_per_face: usize,
+        layout: VertexLayout,
+    ) -> Vec<f32> {
+        let mut data = vec![0.0; count * 4];
+        let mut color = [0.0; 4];
+
+        for vertex in 0..count {
+            if vertex % vertices_per_face.max(1) == 0 {
+                color = self.to_f32_rgba_array();
+            }
+
+            for (channel, value) in color.iter().enumerate() {
+                let index = match layout {
+                    VertexLayout::Interleaved => vertex * 4 + channel,
+                    VertexLayout::Planar => channel * count + vertex,
+                };
+
+                data[index] = *value;
+            }
         }
 
-        if hue == 360 {
-            hue = 359;
+        data
+    }
+
+    /// Generates a random color and returns it as a `Color`.
+    pub fn to_color(&mut self) -> Color {
+        let [r, g, b, a] = self.to_rgba_array();
+
+        Color::new(r, g, b, a)
+    }
+
+    /// Generates a random color and returns it as a canonical string, e.g.
+    /// `hsv:seed=42;hue=blue;a=1;version=v1;h=191;s=30;v=98`, from which
+    /// `from_canonical_string` restores both the color and the generator.
+    ///
+    /// The generator is reseeded with a seed drawn from its current state
+    /// before generating, and that seed is stored along with every setting
+    /// that differs from its default. The string does not depend on the
+    /// locale.
+    ///
+    /// Returns `None`, without generating a color, if the generator has a
+    /// candidate filter, a pick strategy or a mix, as they cannot be stored.
+    pub fn to_canonical_string(&mut self) -> Option<String> {
+        if !CanonicalRecord::is_serializable(self) {
+            return None;
         }
 
-        let h: f32 = hue as f32 / 360.0;
-        let s: f32 = saturation as f32 / 100.0;
-        let v: f32 = brightness as f32 / 100.0;
+        let seed: u64 = self.seed.gen();
+        self.seed = SmallRng::seed_from_u64(seed);
+        let generator = self.clone();
 
-        let h_i = (h * 6.0).floor();
-        let f = h * 6.0 - h_i;
-        let p = v * (1.0 - s);
-        let q = v * (1.0 - f * s);
-        let t = v * (1.0 - (1.0 - f) * s);
+        let (h, s, b) = self.generate_color();
+        self.pick_alpha();
 
-        let (r, g, b) = match h_i as i64 {
-            0 => (v, t, p),
-            1 => (q, v, p),
-            2 => (p, v, t),
-            3 => (p, q, v),
-            4 => (t, p, v),
-            _ => (v, p, q),
+        let record = CanonicalRecord {
+            seed,
+            generator,
+            hsv: [h, s, b],
         };
 
-        [
-            (r * 255.0).floor() as u8,
-            (g * 255.0).floor() as u8,
-            (b * 255.0).floor() as u8,
-        ]
+        Some(record.to_string())
     }
 
-    /// Convert a color from HSV to HSL.
+    /// Generates a single random color and returns it at `count` evenly
+    /// spaced alphas, from `1 / count` up to fully opaque, e.g. for layered
+    /// chart fills and heat overlays.
+    ///
+    /// The alpha setting is ignored.
     ///
     /// Parameters:
-    /// * `hue`: The hue of the color in the range [0, 360).
-    /// * `saturation`: The saturation of the color in the range [0, 100].
-    /// * `brightness`: The brightness of the color in the range [0, 100].
-    fn hsv_to_hsl(&self, hue: i64, saturation: i64, brightness: i64) -> [u32; 3] {
-        let h = hue;
-        let s = saturation as f32 / 100.0;
-        let v = brightness as f32 / 100.0;
-        let mut k = (2.0 - s) * v;
+    /// * `count`: The number of alphas.
+    pub fn alpha_ramp(&mut self, count: usize) -> Palette {
+        let [r, g, b] = self.to_rgb_array();
 
-        if k > 1.0 {
-            k = 2.0 - k;
-        }
+        let colors = (1..=count)
+            .map(|i| {
+                let alpha = (i as f32 / count as f32 * 255.0).round() as u8;
 
-        [
-            h as u32,
-            ((s * v / k * 10000.0) / 100.0) as u32,
-            (k / 2.0 * 100.0) as u32,
-        ]
+                Color::new(r, g, b, alpha)
+            })
+            .collect();
+
+        Palette::new(colors)
     }
 
-    /* Optional Features */
+    /// Generates a single random color and returns `count` slight variations
+    /// of it, e.g. for a crowd of similar but not identical characters.
+    ///
+    /// Each variation shifts the hue and the brightness of the base color by
+    /// at most `spread` degrees and percentage points respectively, keeping
+    /// its saturation.
+    ///
+    /// Parameters:
+    /// * `count`: The number of variations.
+    /// * `spread`: The maximum shift of the hue and brightness. Its sign is
+    ///   ignored.
+    pub fn variations(&mut self, count: usize, spread: i64) -> Palette {
+        let spread = spread.abs();
+        let (h, s, b) = self.generate_color();
+        let alpha = self.pick_alpha();
 
-    /* `rgb` crate support */
+        let colors = (0..count)
+            .map(|_| {
+                let hue = (h + self.seed.gen_range(-spread..=spread)).rem_euclid(360);
+                let brightness = self.random_within_bounds(b, spread);
+                let [r, g, b] = self.hsv_to_rgb(hue, s, brightness);
 
-    /// Generates a random color and returns it as an `Rgb` struct from the `rgb` crate.
-    #[cfg(feature = "rgb_support")]
-    pub fn to_rgb(&mut self) -> Rgb<u8> {
-        Rgb::from(self)
+                Color::new(r, g, b, alpha)
+            })
+            .collect();
+
+        Palette::new(colors)
     }
 
-    /// Generates a random color and returns it as an `Rgba` struct from the `rgb` crate.
-    #[cfg(feature = "rgb_support")]
-    pub fn to_rgba(&mut self) -> rgb::Rgba<u8> {
-        rgb::Rgba::from(self)
+    /// Generates a set of variants of the same hue for each luminosity, e.g.
+    /// for the hover, active and disabled states of a UI component.
+    ///
+    /// The hue is picked once, and every variant takes the same relative
+    /// position within the saturation and brightness ranges of its
+    /// luminosity, so the whole set comes from a single random draw.
+    pub fn generate_luminosity_set(&mut self) -> LuminositySet {
+        let h = self.pick_hue();
+        if let Some(rotation) = self.gamut_rotation.as_mut() {
+            rotation.advance();
+        }
+
+        let (s_min, s_max) = self.color_dictionary.get_saturation_range(&h);
+        let s_position: f32 = self.seed.gen();
+        let b_position: f32 = self.seed.gen();
+        let alpha = self.pick_alpha();
+
+        let lerp = |(min, max): (i64, i64), position: f32| {
+            min + ((max - min) as f32 * position).round() as i64
+        };
+
+        let variant = |luminosity: Option<Luminosity>| {
+            let s = lerp(self.saturation_window(luminosity, s_min, s_max), s_position);
+            let b_min = self.color_dictionary.get_minimum_value(&h, &s);
+            let b = lerp(self.brightness_window(luminosity, b_min, 100), b_position);
+            let [r, g, b] = self.hsv_to_rgb(h, s, b.min(100));
+
+            Color::new(r, g, b, alpha)
+        };
+
+        LuminositySet {
+            light: variant(Some(Luminosity::Light)),
+            default: variant(None),
+            bright: variant(Some(Luminosity::Bright)),
+            dark: variant(Some(Luminosity::Dark)),
+        }
     }
 
-    /* `palette` crate support */
+    /// Generates the colors of a UI component in each of its states from a
+    /// single luminosity set, with a text color readable on each fill.
+    pub fn to_ui_state_colors(&mut self) -> UiStateColors {
+        let set = self.generate_luminosity_set();
 
-    /// Generates a random color and returns it as an `Srgb` struct from the `palette` crate.
-    #[cfg(feature = "palette_support")]
-    pub fn to_srgb(&mut self) -> Srgb {
-        Srgb::from(self)
+        UiStateColors::from_luminosity_set(&set)
     }
 
-    /// Generates a random color and returns it as an `Srgba` struct from the `palette` crate.
-    #[cfg(feature = "palette_support")]
-    pub fn to_srgba(&mut self) -> Srgba {
-        Srgba::from(self)
+    /// Returns a variation of `base` with each RGB channel randomly offset by
+    /// at most the channel jitter, clamped to the range [0, 255].
+    ///
+    /// Only the random number generator runs, so this is cheap enough to vary
+    /// a base color per instance, e.g. for every particle of a particle
+    /// system. The alpha channel is kept as is, and `base` is returned
+    /// unchanged when no channel jitter is set.
+    ///
+    /// Parameters:
+    /// * `base`: The color to vary.
+    pub fn jitter(&mut self, base: Color) -> Color {
+        let jitter = match self.channel_jitter {
+            Some(jitter) => jitter,
+            None => return base,
+        };
+
+        let mut channels = [base.r, base.g, base.b];
+        for (channel, max) in channels.iter_mut().zip(jitter) {
+            let offset = self.seed.gen_range(-(max as i16)..=max as i16);
+
+            *channel = (*channel as i16 + offset).clamp(0, 255) as u8;
+        }
+
+        Color::new(channels[0], channels[1], channels[2], base.a)
     }
 
-    /* `ecolor` crate support` */
+    /// Generates a random color that differs from every given color by at
+    /// least `min_delta_e` (CIEDE2000), and by the minimum lightness gap if
+    /// set.
+    ///
+    /// If no such color is found within the retry policy's attempts, its
+    /// fallback is returned instead.
+    ///
+    /// Parameters:
+    /// * `existing`: The colors to stay away from.
+    /// * `min_delta_e`: The minimum CIEDE2000 difference.
+    pub fn to_distinct_color(&mut self, existing: &[Color], min_delta_e: f32) -> Color {
+        let mut last = self.to_color();
+        let mut separation = self.separation(&last, existing, min_delta_e);
+        let (mut best, mut best_separation) = (last, separation);
+
+        for _attempt in 1..self.retry_policy.max_attempts {
+            if separation >= 1.0 {
+                return last;
+            }
 
-    /// Generates a random color and returns it as an `Color32` struct from the `ecolor` crate.
-    #[cfg(feature = "ecolor_support")]
-    pub fn to_color32(&mut self) -> Color32 {
-        Color32::from(self)
+            trace_event!(attempt = _attempt, separation, "rejected candidate");
+
+            last = self.to_color();
+            separation = self.separation(&last, existing, min_delta_e);
+
+            if separation > best_separation {
+                best = last;
+                best_separation = separation;
+            }
+        }
+
+        if separation >= 1.0 {
+            return last;
+        }
+
+        trace_event!(
+            max_attempts = self.retry_policy.max_attempts,
+            fallback = ?self.retry_policy.on_exhausted,
+            "retries exhausted"
+        );
+
+        match self.retry_policy.on_exhausted {
+            Fallback::BestCandidate => best,
+            Fallback::LastCandidate => last,
+            Fallback::Color(color) => color,
+        }
     }
-}
 
-impl Default for RandomColor {
-    fn default() -> Self {
-        RandomColor::new()
+    /// Generates `count` random colors and returns a `ValueMapper` that spreads
+    /// them evenly across the domain from `min` to `max`, ordered from the
+    /// darkest to the lightest so the mapping reads as a sequential scale.
+    ///
+    /// Parameters:
+    /// * `min`: The lowest value of the domain.
+    /// * `max`: The highest value of the domain.
+    /// * `count`: The number of anchor colors.
+    pub fn to_value_mapper(&mut self, min: f32, max: f32, count: usize) -> ValueMapper {
+        let mut colors: Vec<Color> = (0..count).map(|_| self.to_color()).collect();
+        colors.sort_by(|a, b| a.to_lab()[0].total_cmp(&b.to_lab()[0]));
+
+        let anchors: Vec<(f32, Color)> = colors
+            .into_iter()
+            .enumerate()
+            .map(|(i, color)| {
+                let t = match count {
+                    1 => 0.0,
+                    _ => i as f32 / (count - 1) as f32,
+                };
+
+                (min + (max - min) * t, color)
+            })
+            .collect();
+
+        let mut mapper = ValueMapper::new(&anchors);
+        mapper.gamut_mapping(self.gamut_mapping);
+
+        mapper
     }
-}
 
-#[cfg(feature = "rgb_support")]
-impl From<RandomColor> for Rgb<u8> {
-    fn from(value: RandomColor) -> Self {
-        let rgb = value.into_rgb_array();
+    /// Generates a palette of random colors, honoring the minimum lightness
+    /// gap if set.
+    ///
+    /// Parameters:
+    /// * `count`: The number of colors.
+    pub fn to_palette(&mut self, count: usize) -> Palette {
+        match self.min_lightness_gap {
+            Some(_) => self.to_distinct_palette(count, 0.0),
+            None => Palette::new((0..count).map(|_| self.to_color()).collect()),
+        }
+    }
 
-        Rgb {
-            r: rgb[0],
-            g: rgb[1],
-            b: rgb[2],
+    /// Generates a palette of random colors that differ from each other by at
+    /// least `min_delta_e` (CIEDE2000), as far as the settings allow.
+    ///
+    /// Parameters:
+    /// * `count`: The number of colors.
+    /// * `min_delta_e`: The minimum CIEDE2000 difference between colors.
+    pub fn to_distinct_palette(&mut self, count: usize, min_delta_e: f32) -> Palette {
+        let mut colors = Vec::with_capacity(count);
+
+        for _ in 0..count {
+            let color = self.to_distinct_color(&colors, min_delta_e);
+            colors.push(color);
         }
+
+        Palette::new(colors)
     }
-}
 
-#[cfg(feature = "rgb_support")]
-impl From<&mut RandomColor> for Rgb<u8> {
-    fn from(value: &mut RandomColor) -> Self {
-        let rgb = value.to_rgb_array();
+    /// Generates a sequential palette shaped like a well-known colormap, with
+    /// its anchors slightly perturbed by the seed.
+    ///
+    /// Only the chromatic components of the anchors are perturbed, so the
+    /// palette keeps the perceptual lightness ordering of the original map.
+    ///
+    /// Anchors pushed outside the sRGB gamut are brought back into it with
+    /// the gamut mapping setting.
+    ///
+    /// Parameters:
+    /// * `colormap`: The colormap to perturb.
+    /// * `count`: The number of colors.
+    pub fn to_colormap(&mut self, colormap: Colormap, count: usize) -> Palette {
+        let anchors: Vec<[f32; 3]> = colormap
+            .anchors()
+            .iter()
+            .map(|rgb| {
+                let [l, a, b] = conversions::rgb_to_lab(rgb.map(|c| c as f32 / 255.0));
+
+                [
+                    l,
+                    a + self.seed.gen_range(-COLORMAP_JITTER..COLORMAP_JITTER),
+                    b + self.seed.gen_range(-COLORMAP_JITTER..COLORMAP_JITTER),
+                ]
+            })
+            .collect();
+
+        let colors = (0..count)
+            .map(|i| {
+                let t = match count {
+                    1 => 0.0,
+                    _ => i as f32 / (count - 1) as f32,
+                };
+                let position = t * (anchors.len() - 1) as f32;
+                let index = (position.floor() as usize).min(anchors.len() - 2);
+                let local = position - index as f32;
+
+                let (from, to) = (anchors[index], anchors[index + 1]);
+                let lab = [0, 1, 2].map(|c| from[c] + (to[c] - from[c]) * local);
+                let [r, g, b] = self.gamut_mapping.map(conversions::lab_to_rgb(lab));
+
+                Color::new(r, g, b, 255)
+            })
+            .collect();
+
+        Palette::new(colors)
+    }
 
-        Rgb {
-            r: rgb[0],
-            g: rgb[1],
-            b: rgb[2],
+    /// Generates a random color and returns it packed as RGB565, with 5 bits
+    /// for red, 6 for green and 5 for blue, as used by small TFT and OLED
+    /// displays.
+    pub fn to_rgb565(&mut self) -> u16 {
+        let [r, g, b] = self.to_rgb_array();
+
+        (r as u16 >> 3) << 11 | (g as u16 >> 2) << 5 | b as u16 >> 3
+    }
+
+    /// Generates a random color and returns its Rec. 709 luma, a quick
+    /// estimate of its perceived brightness.
+    pub fn to_luma(&mut self) -> u8 {
+        (self.to_f32_luma() * 255.0).round() as u8
+    }
+
+    /// Generates a random color and returns its Rec. 709 luma, in the range
+    /// [0.0, 1.0].
+    ///
+    /// Luma is computed from the gamma-encoded channels, unlike the relative
+    /// luminance used for contrast ratios.
+    pub fn to_f32_luma(&mut self) -> f32 {
+        let [r, g, b] = self.to_f32_rgb_array();
+
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// Generates a random color and returns its WCAG relative luminance, in
+    /// the range [0.0, 1.0], as used to compute contrast ratios.
+    pub fn relative_luminance(&mut self) -> f32 {
+        self.to_color().relative_luminance()
+    }
+
+    /// Generates a random color and returns the index of the closest color in
+    /// the xterm 256-color palette, for terminals without truecolor support.
+    ///
+    /// Only the 6×6×6 color cube (16-231) and the grayscale ramp (232-255)
+    /// are considered, as the first 16 colors vary between terminals.
+    pub fn to_ansi256(&mut self) -> u8 {
+        const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+        let rgb = self.to_rgb_array();
+        let distance = |other: [u8; 3]| -> i32 {
+            rgb.iter()
+                .zip(other)
+                .map(|(a, b)| (*a as i32 - b as i32).pow(2))
+                .sum()
+        };
+
+        let [r, g, b] = rgb.map(|c| {
+            (0..CUBE_LEVELS.len())
+                .min_by_key(|i| (CUBE_LEVELS[*i] as i32 - c as i32).abs())
+                .unwrap() as u8
+        });
+        let cube = [r, g, b].map(|i| CUBE_LEVELS[i as usize]);
+
+        let average = rgb.iter().map(|c| *c as f32).sum::<f32>() / 3.0;
+        let step = ((average - 8.0) / 10.0).round().clamp(0.0, 23.0) as u8;
+        let gray = 8 + 10 * step;
+
+        if distance(cube) <= distance([gray; 3]) {
+            16 + 36 * r + 6 * g + b
+        } else {
+            232 + step
+        }
+    }
+
+    /// Generates a random color and returns it as a gamma-corrected GRB array
+    /// ready to be sent to WS2812 (NeoPixel) addressable LEDs.
+    ///
+    /// LEDs respond linearly to their PWM input, so colors that look right on
+    /// a screen appear washed out unless a gamma curve is applied first.
+    pub fn to_ws2812_grb(&mut self) -> [u8; 3] {
+        let [r, g, b] = self.to_rgb_array();
+
+        [self.led_gamma(g), self.led_gamma(r), self.led_gamma(b)]
+    }
+
+    /// Generates a random color and returns it in the HSB ranges used by smart
+    /// light APIs such as Philips Hue: hue in [0, 65535], saturation and
+    /// brightness in [0, 254].
+    pub fn to_hue_hsb(&mut self) -> [u16; 3] {
+        let (h, s, b) = self.generate_color();
+
+        [
+            (h.rem_euclid(360) as f32 / 360.0 * 65535.0).round() as u16,
+            (s as f32 / 100.0 * 254.0).round() as u16,
+            (b as f32 / 100.0 * 254.0).round() as u16,
+        ]
+    }
+
+    /// Generates a random color and returns it as DMX channel values for an
+    /// RGB fixture, scaled by a master dimmer.
+    ///
+    /// Parameters:
+    /// * `master`: The master dimmer level in the range [0.0, 1.0].
+    pub fn to_dmx(&mut self, master: f32) -> [u8; 3] {
+        let [r, g, b] = self.to_rgb_array();
+
+        [
+            self.dim(r, master),
+            self.dim(g, master),
+            self.dim(b, master),
+        ]
+    }
+
+    /// Generates a random color and returns it as DMX channel values for an
+    /// RGBW fixture, scaled by a master dimmer.
+    ///
+    /// The white channel takes over the part of the color shared by all three
+    /// RGB channels.
+    ///
+    /// Parameters:
+    /// * `master`: The master dimmer level in the range [0.0, 1.0].
+    pub fn to_dmx_rgbw(&mut self, master: f32) -> [u8; 4] {
+        let rgb = self.to_rgb_array();
+        let [r, g, b, w] = self.rgb_to_rgbw(rgb, WhiteExtraction::MinChannel);
+
+        [
+            self.dim(r, master),
+            self.dim(g, master),
+            self.dim(b, master),
+            self.dim(w, master),
+        ]
+    }
+
+    /// Generates a random color and returns it as an RGBW array, for LED
+    /// strips and fixtures with a dedicated white emitter.
+    ///
+    /// Parameters:
+    /// * `strategy`: How the white component is separated from the color.
+    pub fn to_rgbw_array(&mut self, strategy: WhiteExtraction) -> [u8; 4] {
+        let rgb = self.to_rgb_array();
+
+        self.rgb_to_rgbw(rgb, strategy)
+    }
+
+    /// Generates a random color and returns it as an 8-bit YCbCr array in the
+    /// limited range used for video, with the luma in the range [16, 235]
+    /// and the chroma in the range [16, 240].
+    ///
+    /// Parameters:
+    /// * `standard`: The standard whose luma coefficients are used.
+    pub fn to_ycbcr_array(&mut self, standard: YCbCrStandard) -> [u8; 3] {
+        let [r, g, b] = self.to_f32_rgb_array();
+        let (kr, kb) = standard.coefficients();
+
+        let y = kr * r + (1.0 - kr - kb) * g + kb * b;
+        let cb = (b - y) / (2.0 * (1.0 - kb));
+        let cr = (r - y) / (2.0 * (1.0 - kr));
+
+        [
+            (16.0 + 219.0 * y).round() as u8,
+            (128.0 + 224.0 * cb).round() as u8,
+            (128.0 + 224.0 * cr).round() as u8,
+        ]
+    }
+
+    /// Generates two random colors and returns a CSS `color-mix()` expression
+    /// blending them in the OKLab space, e.g.
+    /// `color-mix(in oklab, #aeecf9 60%, #3e0496)`.
+    ///
+    /// Parameters:
+    /// * `percentage`: The share of the first color in the mix, in the range
+    ///   [0, 100].
+    pub fn to_color_mix_string(&mut self, percentage: u8) -> String {
+        let first = self.to_hex();
+        let second = self.to_hex();
+
+        format!(
+            "color-mix(in oklab, {} {}%, {})",
+            first,
+            percentage.min(100),
+            second
+        )
+    }
+
+    /// Generates a random 5×5 symmetric identicon.
+    ///
+    /// The foreground color follows the current settings, while the
+    /// background is a light gray drawn from the same seed, with the other
+    /// settings, such as the color dictionary, kept.
+    pub fn to_identicon(&mut self) -> Identicon {
+        let foreground = self.to_rgb_array();
+
+        // The settings that take precedence over the hue and luminosity are
+        // cleared so that the background stays a light gray.
+        let mut background = self.clone();
+        background.exact_hue = None;
+        background.hue_mask = None;
+        background.gamut_weights = None;
+        background.gamut_rotation = None;
+        background.near = None;
+        background.luminosity_blend = None;
+        background.style = None;
+        background.saturation = None;
+        background.brightness = None;
+        background.candidate_filter = None;
+        background.mix = None;
+        background.pick_strategy = None;
+        background
+            .hue(Gamut::Monochrome)
+            .luminosity(Luminosity::Light)
+            .seed(self.seed.gen::<u64>());
+
+        let mut pattern = [[false; IDENTICON_SIZE]; IDENTICON_SIZE];
+        for row in pattern.iter_mut() {
+            for cell in row.iter_mut().take(IDENTICON_SIZE / 2 + 1) {
+                *cell = self.seed.gen_bool(0.5);
+            }
+        }
+
+        Identicon::new(pattern, foreground, background.to_rgb_array())
+    }
+
+    /// Generates a random color and reports the error introduced by each of
+    /// the lossy output paths when converting it.
+    pub fn audit_conversions(&mut self) -> ConversionAudit {
+        let (h, s, b) = self.generate_color();
+        let rgb = self.hsv_to_rgb(h, s, b);
+        let hsl = self.hsv_to_hsl(h, s, b);
+
+        let exact_rgb = conversions::hsv_to_rgb([h as f32, s as f32 / 100.0, b as f32 / 100.0]);
+        let quantized_rgb = rgb.map(|c| c as f32 / 255.0);
+        let rgb_error = (0..3)
+            .map(|i| (exact_rgb[i] - quantized_rgb[i]).abs() * 255.0)
+            .fold(0.0, f32::max);
+        let rgb_delta_e = difference::ciede2000(
+            conversions::rgb_to_lab(exact_rgb),
+            conversions::rgb_to_lab(quantized_rgb),
+        );
+
+        let [rh, rs, rb] = Color::new(rgb[0], rgb[1], rgb[2], 255).to_hsv_array();
+        let hue_error = (rh as i64 - h).rem_euclid(360);
+        let hsv_round_trip_error = hue_error
+            .min(360 - hue_error)
+            .max((rs as i64 - s).abs())
+            .max((rb as i64 - b).abs()) as u32;
+
+        let [eh, es, el] =
+            conversions::hsv_to_hsl([h.rem_euclid(360) as f32, s as f32 / 100.0, b as f32 / 100.0]);
+        let exact_hsl = [eh, es * 100.0, el * 100.0];
+        let hsl_error = (0..3)
+            .map(|i| (exact_hsl[i] - hsl[i] as f32).abs())
+            .fold(0.0, f32::max);
+
+        ConversionAudit {
+            hsv: [h as u32, s as u32, b as u32],
+            rgb,
+            rgb_error,
+            rgb_delta_e,
+            hsv_round_trip_error,
+            hsl,
+            hsl_error,
+        }
+    }
+
+    /// Transforms the `RandomColor` into a `u8` array with the color's RGB values.
+    pub fn into_rgb_array(self) -> [u8; 3] {
+        self.clone().to_rgb_array()
+    }
+
+    /// Transforms the `RandomColor` into a `u8` array with the color's RGBA values.
+    pub fn into_rgba_array(self) -> [u8; 4] {
+        self.clone().to_rgba_array()
+    }
+
+    /// Transforms the `RandomColor` into a `f32` array with the color's RGB values.
+    pub fn into_f32_rgb_array(self) -> [f32; 3] {
+        self.clone().to_f32_rgb_array()
+    }
+
+    /// Transforms the `RandomColor` into a `f32` array with the color's RGBA values.
+    pub fn into_f32_rgba_array(self) -> [f32; 4] {
+        self.clone().to_f32_rgba_array()
+    }
+
+    /// Generates a random color based on the settings, running the candidate
+    /// filter if any.
+    fn generate_color(&mut self) -> (i64, i64, i64) {
+        #[cfg(feature = "tracing_support")]
+        let _span = tracing::debug_span!("generate_color").entered();
+
+        let (h, s, b) = self.generate_filtered_color();
+
+        if let Some(rotation) = self.gamut_rotation.as_mut() {
+            rotation.advance();
+        }
+
+        match self.grayscale {
+            true => (h, 0, b),
+            false => (h, s, b),
+        }
+    }
+
+    /// Generates random color candidates until one passes the candidate
+    /// filter, if any.
+    fn generate_filtered_color(&mut self) -> (i64, i64, i64) {
+        let filter = match self.candidate_filter.clone() {
+            Some(filter) => filter,
+            None => return self.generate_candidate(),
+        };
+
+        let mut candidate = (0, 0, 0);
+
+        for _attempt in 0..self.retry_policy.max_attempts.max(1) {
+            candidate = self.generate_candidate();
+            let (h, s, b) = candidate;
+
+            match filter.apply([h, s, b]) {
+                Candidate::Accept => return candidate,
+                Candidate::Adjust(h, s, b) => {
+                    return (h.rem_euclid(360), s.clamp(0, 100), b.clamp(0, 100))
+                }
+                Candidate::Reject => {
+                    trace_event!(
+                        attempt = _attempt,
+                        hue = h,
+                        saturation = s,
+                        brightness = b,
+                        "candidate rejected by filter"
+                    );
+                }
+            }
+        }
+
+        match self.retry_policy.on_exhausted {
+            Fallback::Color(color) => {
+                let [h, s, b] = color.to_hsv_array();
+
+                (h as i64, s as i64, b as i64)
+            }
+            _ => candidate,
+        }
+    }
+
+    /// Generates a random color candidate based on the settings.
+    fn generate_candidate(&mut self) -> (i64, i64, i64) {
+        if let Some(mut mix) = self.mix.take() {
+            let generator = match self.seed.gen_bool(mix.weight as f64) {
+                true => &mut mix.a,
+                false => &mut mix.b,
+            };
+
+            std::mem::swap(&mut generator.seed, &mut self.seed);
+            let candidate = generator.generate_color();
+            std::mem::swap(&mut generator.seed, &mut self.seed);
+
+            self.mix = Some(mix);
+
+            return candidate;
+        }
+
+        let (h, s, b) = match self.pick_strategy.clone() {
+            Some(strategy) => {
+                let h = strategy.get().pick_hue(self);
+                let s = strategy.get().pick_saturation(self, h);
+                let b = strategy.get().pick_brightness(self, h, s);
+
+                (h, s, b)
+            }
+            None => {
+                let h = self.pick_hue();
+                let s = self.pick_saturation(&h);
+                let b = self.pick_brightness(&h, &s);
+
+                (h, s, b)
+            }
+        };
+
+        trace_event!(hue = h, saturation = s, brightness = b, luminosity = ?self.luminosity, "generated color");
+
+        (h, s, b)
+    }
+
+    /// Picks a random hue based on the hue setting.
+    pub(crate) fn pick_hue(&mut self) -> i64 {
+        if let Some(near) = self.near {
+            trace_event!(
+                hue = near.hue,
+                tolerance = near.tolerance,
+                "picking hue near reference color"
+            );
+
+            return self
+                .random_hue_within(near.hue - near.tolerance, near.hue + near.tolerance + 1)
+                .rem_euclid(360);
+        }
+
+        if let Some(hue) = self.exact_hue {
+            trace_event!(hue = hue, "picking exact hue");
+
+            // The hue mask does not apply, but the hue is still drawn so the
+            // saturation and brightness of a seed do not shift.
+            return self.random_within(hue, hue + 1);
+        }
+
+        if let Some(gamut) = self.gamut_rotation.as_ref().and_then(|r| r.peek()) {
+            trace_event!(gamut = ?gamut, "picking hue from rotated gamut");
+
+            let range = self.color_dictionary.get_color_from_gamut(&gamut).range;
+            return self.random_hue_within(range[0], range[1]);
+        }
+
+        if let Some(gamut) = self.pick_weighted_gamut() {
+            trace_event!(gamut = ?gamut, "picking hue from weighted gamut");
+
+            let range = self.color_dictionary.get_color_from_gamut(&gamut).range;
+            return self.random_hue_within(range[0], range[1]);
+        }
+
+        trace_event!(gamut = ?self.hue, "picking hue from gamut");
+
+        match self.hue {
+            None => self.random_hue_within(0, 361),
+            Some(ref gamut) => {
+                let range = self.color_dictionary.get_color_from_gamut(gamut).range;
+                self.random_hue_within(range[0], range[1])
+            }
+        }
+    }
+
+    /// Picks a random hue within the given range, honoring the hue mask
+    /// setting if any.
+    ///
+    /// Parameters:
+    /// * `min`: The minimum hue, which may be negative.
+    /// * `max`: The maximum hue, excluded.
+    fn random_hue_within(&mut self, min: i64, max: i64) -> i64 {
+        let mask = match self.hue_mask.as_deref() {
+            Some(mask) if mask.contains(&true) => mask,
+            _ => return self.random_within(min, max),
+        };
+
+        let mut allowed: Vec<i64> = (min..max.max(min + 1))
+            .filter(|hue| mask[hue.rem_euclid(360) as usize])
+            .collect();
+
+        if allowed.is_empty() {
+            allowed = (0..360).filter(|hue| mask[*hue as usize]).collect();
+        }
+
+        let index = self.random_within(0, allowed.len() as i64);
+
+        allowed[index as usize]
+    }
+
+    /// Picks a random gamut based on the gamut weights setting, if any.
+    fn pick_weighted_gamut(&mut self) -> Option<Gamut> {
+        let total: u32 = self.gamut_weights.as_ref()?.iter().map(|(_, w)| w).sum();
+
+        if total == 0 {
+            return None;
+        }
+
+        let mut target = self.random_within(0, total as i64);
+
+        self.gamut_weights
+            .as_ref()?
+            .iter()
+            .find_map(|(gamut, weight)| {
+                target -= *weight as i64;
+
+                (target < 0).then_some(*gamut)
+            })
+    }
+
+    /// Picks a random saturation value based on the hue, saturation and
+    /// luminosity settings.
+    ///
+    /// Parameters:
+    /// * `hue`: The hue of the color.
+    pub(crate) fn pick_saturation(&mut self, hue: &i64) -> i64 {
+        let s_range: (i64, i64) = self.color_dictionary.get_saturation_range(hue);
+
+        let s_min = s_range.0;
+        let s_max = s_range.1;
+
+        if let Some(near) = self.near {
+            return self.random_within_bounds(near.saturation, near.tolerance);
+        }
+
+        if let Some(range) = self.saturation {
+            return self
+                .random_biased_within(range.min, range.max + 1, self.saturation_bias)
+                .min(100);
+        }
+
+        let (min, max) = match (self.style, self.luminosity_blend) {
+            (Some(style), _) => style.saturation_window(s_min, s_max),
+            (None, Some(blend)) => blend.interpolate(
+                self.saturation_window(Some(blend.from), s_min, s_max),
+                self.saturation_window(Some(blend.to), s_min, s_max),
+            ),
+            (None, None) => self.saturation_window(self.luminosity, s_min, s_max),
+        };
+
+        self.random_biased_within(min, max, self.saturation_bias)
+    }
+
+    /// Returns the range saturation values are picked from for a luminosity.
+    ///
+    /// Parameters:
+    /// * `luminosity`: The luminosity setting.
+    /// * `s_min`: The minimum saturation of the hue.
+    /// * `s_max`: The maximum saturation of the hue.
+    fn saturation_window(
+        &self,
+        luminosity: Option<Luminosity>,
+        s_min: i64,
+        s_max: i64,
+    ) -> (i64, i64) {
+        match luminosity {
+            Some(Luminosity::Random) => (0, 100),
+            Some(Luminosity::Bright) => (55, s_max),
+            Some(Luminosity::Dark) => (s_max - 10, s_max),
+            Some(Luminosity::Light) => (s_min, 55),
+            _ => (s_min, s_max),
+        }
+    }
+
+    /// Picks a random brightness value based on the hue and saturation, as well
+    /// as the brightness and luminosity settings.
+    ///
+    /// Parameters:
+    /// * `hue`: The hue of the color.
+    /// * `saturation`: The saturation of the color.
+    pub(crate) fn pick_brightness(&mut self, hue: &i64, saturation: &i64) -> i64 {
+        let b_min = self.color_dictionary.get_minimum_value(hue, saturation);
+        let b_max = 100;
+
+        if let Some(near) = self.near {
+            return self.random_within_bounds(near.brightness, near.tolerance);
+        }
+
+        if let Some(range) = self.brightness {
+            return self
+                .random_biased_within(range.min, range.max + 1, self.value_bias)
+                .min(100);
+        }
+
+        if self.sampling == Sampling::Edge {
+            return self.random_within(b_min, (b_min + EDGE_TOLERANCE).min(b_max));
+        }
+
+        let (min, max) = match (self.style, self.luminosity_blend) {
+            (Some(style), _) => style.brightness_window(),
+            (None, Some(blend)) => blend.interpolate(
+                self.brightness_window(Some(blend.from), b_min, b_max),
+                self.brightness_window(Some(blend.to), b_min, b_max),
+            ),
+            (None, None) => self.brightness_window(self.luminosity, b_min, b_max),
+        };
+
+        self.random_biased_within(min, max, self.value_bias)
+    }
+
+    /// Returns the range brightness values are picked from for a luminosity.
+    ///
+    /// Parameters:
+    /// * `luminosity`: The luminosity setting.
+    /// * `b_min`: The minimum brightness of the hue and saturation.
+    /// * `b_max`: The maximum brightness.
+    fn brightness_window(
+        &self,
+        luminosity: Option<Luminosity>,
+        b_min: i64,
+        b_max: i64,
+    ) -> (i64, i64) {
+        match luminosity {
+            Some(Luminosity::Random) => (0, 100),
+            Some(Luminosity::Light) => ((b_max + b_min) / 2, b_max),
+            Some(Luminosity::Dark) => (b_min, b_min + 20),
+            _ => (b_min, b_max),
+        }
+    }
+
+    /// Picks the alpha value of a color from the alpha setting, or at random
+    /// if there is none.
+    fn pick_alpha(&mut self) -> u8 {
+        match self.alpha {
+            Some(alpha) => (alpha * 255.0) as u8,
+            None => self.random_within(0, 255) as u8,
+        }
+    }
+
+    /// Generates a random i64 within the given range.
+    ///
+    /// This function first ensures that `min` is less than or equal to `max`.
+    /// If `min` is equal to `max`, it increments `max` by 1 to ensure that the
+    /// range is not empty. It uses the `SmallRng` in the seed property to
+    /// generate the random number.
+    ///
+    /// Parameters:
+    /// * `min`: The minimum value of the range.
+    /// * `max`: The maximum value of the range.
+    fn random_within(&mut self, mut min: i64, mut max: i64) -> i64 {
+        if min > max {
+            std::mem::swap(&mut min, &mut max);
+        }
+
+        if min == max {
+            max += 1;
+        }
+
+        self.seed.gen_range(min..max)
+    }
+
+    /// Generates a random i64 within the given range, reshaped by an easing
+    /// curve if any. Without a curve, this is the same as `random_within`.
+    ///
+    /// Parameters:
+    /// * `min`: The minimum value of the range.
+    /// * `max`: The maximum value of the range.
+    /// * `curve`: The easing curve to sample with.
+    fn random_biased_within(&mut self, min: i64, max: i64, curve: Option<Curve>) -> i64 {
+        let curve = match curve {
+            Some(curve) => curve,
+            None => return self.random_within(min, max),
+        };
+
+        let (min, max) = (min.min(max), min.max(max));
+
+        if max - min <= 1 {
+            return min;
+        }
+
+        let t = curve.apply(self.seed.gen_range(0.0..1.0));
+
+        (min + (t * (max - min) as f32) as i64).min(max - 1)
+    }
+
+    /// Generates a random i64 within `tolerance` of `value`, both ends
+    /// included, without leaving the range [0, 100].
+    ///
+    /// Parameters:
+    /// * `value`: The center of the range.
+    /// * `tolerance`: The maximum deviation from `value`.
+    fn random_within_bounds(&mut self, value: i64, tolerance: i64) -> i64 {
+        let min = (value - tolerance).clamp(0, 100);
+        let max = (value + tolerance).clamp(0, 100);
+
+        self.random_within(min, max + 1).min(100)
+    }
+
+    /// Generates a random color and converts it from HSV to `f32` RGB,
+    /// without quantizing it to 8 bits.
+    fn generate_exact_rgb(&mut self) -> [f32; 3] {
+        let (h, s, b) = self.generate_color();
+
+        conversions::hsv_to_rgb([h as f32, s as f32 / 100.0, b as f32 / 100.0])
+    }
+
+    /// Converts a color from HSV to `f32` RGB, through the 8-bit channels
+    /// with `AlgorithmVersion::V1` and without quantizing it with
+    /// `AlgorithmVersion::V2`.
+    ///
+    /// Parameters:
+    /// * `hue`: The hue of the color in the range [0, 360).
+    /// * `saturation`: The saturation of the color in the range [0, 100].
+    /// * `brightness`: The brightness of the color in the range [0, 100].
+    fn hsv_to_f32_rgb(&self, hue: i64, saturation: i64, brightness: i64) -> [f32; 3] {
+        match self.algorithm_version {
+            AlgorithmVersion::V1 => self
+                .hsv_to_rgb(hue, saturation, brightness)
+                .map(|c| c as f32 / 255.0),
+            AlgorithmVersion::V2 => conversions::hsv_to_rgb([
+                hue as f32,
+                saturation as f32 / 100.0,
+                brightness as f32 / 100.0,
+            ]),
+        }
+    }
+
+    /// Convert a color from HSV to RGB.
+    ///
+    /// Parameters:
+    /// * `hue`: The hue of the color in the range [0, 360).
+    /// * `saturation`: The saturation of the color in the range [0, 100].
+    /// * `brightness`: The brightness of the color in the range [0, 100].
+    fn hsv_to_rgb(&self, mut hue: i64, saturation: i64, brightness: i64) -> [u8; 3] {
+        if saturation == 0 {
+            let value = brightness as f32 / 100.0 * 255.0;
+
+            return match self.algorithm_version {
+                AlgorithmVersion::V1 => [value.floor() as u8; 3],
+                AlgorithmVersion::V2 => [value.round() as u8; 3],
+            };
+        }
+
+        if self.algorithm_version == AlgorithmVersion::V2 {
+            let hsv = [
+                hue as f32,
+                saturation as f32 / 100.0,
+                brightness as f32 / 100.0,
+            ];
+
+            return conversions::hsv_to_rgb(hsv).map(|c| (c * 255.0).round() as u8);
+        }
+
+        if hue == 0 {
+            hue = 1;
+        }
+
+        if hue == 360 {
+            hue = 359;
+        }
+
+        let h: f32 = hue as f32 / 360.0;
+        let s: f32 = saturation as f32 / 100.0;
+        let v: f32 = brightness as f32 / 100.0;
+
+        let h_i = (h * 6.0).floor();
+        let f = h * 6.0 - h_i;
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - f * s);
+        let t = v * (1.0 - (1.0 - f) * s);
+
+        let (r, g, b) = match h_i as i64 {
+            0 => (v, t, p),
+            1 => (q, v, p),
+            2 => (p, v, t),
+            3 => (p, q, v),
+            4 => (t, p, v),
+            _ => (v, p, q),
+        };
+
+        [
+            (r * 255.0).floor() as u8,
+            (g * 255.0).floor() as u8,
+            (b * 255.0).floor() as u8,
+        ]
+    }
+
+    /// Convert a color from HSV to HSL, truncating the components to
+    /// integers.
+    ///
+    /// Parameters:
+    /// * `hue`: The hue of the color, which may be negative for reds.
+    /// * `saturation`: The saturation of the color in the range [0, 100].
+    /// * `brightness`: The brightness of the color in the range [0, 100].
+    fn hsv_to_hsl(&self, hue: i64, saturation: i64, brightness: i64) -> [u32; 3] {
+        let hsv = [
+            hue.rem_euclid(360) as f32,
+            saturation as f32 / 100.0,
+            brightness as f32 / 100.0,
+        ];
+        let [h, s, l] = conversions::hsv_to_hsl(hsv);
+
+        [h as u32, (s * 100.0) as u32, (l * 100.0) as u32]
+    }
+
+    /// Convert a color from HSV to HWB.
+    ///
+    /// Parameters:
+    /// * `hue`: The hue of the color, which may be negative for reds.
+    /// * `saturation`: The saturation of the color in the range [0, 100].
+    /// * `brightness`: The brightness of the color in the range [0, 100].
+    fn hsv_to_hwb(&self, hue: i64, saturation: i64, brightness: i64) -> [u32; 3] {
+        let whiteness = (100 - saturation) as f32 * brightness as f32 / 100.0;
+
+        [
+            hue.rem_euclid(360) as u32,
+            whiteness.round() as u32,
+            (100 - brightness) as u32,
+        ]
+    }
+
+    /// Applies the gamma curve commonly used for addressable LEDs to a channel.
+    ///
+    /// Parameters:
+    /// * `channel`: The channel value to correct.
+    fn led_gamma(&self, channel: u8) -> u8 {
+        ((channel as f32 / 255.0).powf(LED_GAMMA) * 255.0 + 0.5) as u8
+    }
+
+    /// Returns how well a color is separated from a set of colors, relative
+    /// to the required CIEDE2000 difference and minimum lightness gap.
+    ///
+    /// A value of 1.0 or more means every requirement is met. The value is
+    /// infinite if the set is empty or nothing is required.
+    ///
+    /// Parameters:
+    /// * `color`: The color to compare.
+    /// * `others`: The colors to compare against.
+    /// * `min_delta_e`: The required CIEDE2000 difference.
+    fn separation(&self, color: &Color, others: &[Color], min_delta_e: f32) -> f32 {
+        let lightness = color.to_lab()[0];
+
+        others
+            .iter()
+            .map(|other| {
+                let delta_e = match min_delta_e > 0.0 {
+                    true => color.delta_e(other) / min_delta_e,
+                    false => f32::INFINITY,
+                };
+                let gap = match self.min_lightness_gap {
+                    Some(gap) if gap > 0.0 => (lightness - other.to_lab()[0]).abs() / gap,
+                    _ => f32::INFINITY,
+                };
+
+                delta_e.min(gap)
+            })
+            .fold(f32::INFINITY, f32::min)
+    }
+
+    /// Convert a color from RGB to CMYK.
+    ///
+    /// Parameters:
+    /// * `rgb`: The color with channels in the range [0.0, 1.0].
+    fn rgb_to_cmyk(&self, rgb: [f32; 3]) -> [f32; 4] {
+        let [r, g, b] = rgb;
+        let k = 1.0 - r.max(g).max(b);
+
+        if k >= 1.0 {
+            return [0.0, 0.0, 0.0, 1.0];
+        }
+
+        [
+            (1.0 - r - k) / (1.0 - k),
+            (1.0 - g - k) / (1.0 - k),
+            (1.0 - b - k) / (1.0 - k),
+            k,
+        ]
+    }
+
+    /// Separates a white component from an RGB color.
+    ///
+    /// Parameters:
+    /// * `rgb`: The RGB color.
+    /// * `strategy`: How the white component is separated from the color.
+    fn rgb_to_rgbw(&self, rgb: [u8; 3], strategy: WhiteExtraction) -> [u8; 4] {
+        let [r, g, b] = rgb;
+        let min = r.min(g).min(b);
+        let max = r.max(g).max(b);
+
+        let w = match strategy {
+            WhiteExtraction::MinChannel => min,
+            WhiteExtraction::Luminance => {
+                if max == 0 {
+                    0
+                } else {
+                    let luma = 0.2126 * r as f32 + 0.7152 * g as f32 + 0.0722 * b as f32;
+                    (luma * min as f32 / max as f32).round() as u8
+                }
+            }
+        };
+
+        [
+            r.saturating_sub(w),
+            g.saturating_sub(w),
+            b.saturating_sub(w),
+            w,
+        ]
+    }
+
+    /// Scales a channel by a dimmer level, clamping the level to [0.0, 1.0].
+    ///
+    /// Parameters:
+    /// * `channel`: The channel value to scale.
+    /// * `level`: The dimmer level.
+    fn dim(&self, channel: u8, level: f32) -> u8 {
+        (channel as f32 * level.clamp(0.0, 1.0)).round() as u8
+    }
+
+    /* Optional Features */
+
+    /* Soft-proofing support */
+
+    /// Generates a random color and returns how it appears on the given
+    /// display, e.g. a kiosk screen with a limited gamut or a warm white
+    /// point.
+    ///
+    /// Parameters:
+    /// * `profile`: The profile of the target display.
+    #[cfg(feature = "soft_proofing")]
+    pub fn to_soft_proof(&mut self, profile: &DisplayProfile) -> Color {
+        profile.proof_mapped(self.to_color(), self.gamut_mapping)
+    }
+
+    /* ANSI preview support */
+
+    /// Generates a random color and returns it rendered as a terminal color
+    /// block with its hex label, using 24-bit ANSI escape codes.
+    #[cfg(feature = "ansi_preview")]
+    pub fn preview_string(&mut self) -> String {
+        self.to_color().preview_string()
+    }
+
+    /// Generates a random color and prints it to the standard output as a
+    /// terminal color block with its hex label.
+    #[cfg(feature = "ansi_preview")]
+    pub fn print_preview(&mut self) {
+        println!("{}", self.preview_string());
+    }
+
+    /* `rgb` crate support */
+
+    /// Generates a random color and returns it as an `Rgb` struct from the `rgb` crate.
+    #[cfg(feature = "rgb_support")]
+    pub fn to_rgb(&mut self) -> Rgb<u8> {
+        Rgb::from(self)
+    }
+
+    /// Generates a random color and returns it as an `Rgba` struct from the `rgb` crate.
+    #[cfg(feature = "rgb_support")]
+    pub fn to_rgba(&mut self) -> rgb::Rgba<u8> {
+        rgb::Rgba::from(self)
+    }
+
+    /* `palette` crate support */
+
+    /// Generates a random color and returns it as an `Srgb` struct from the `palette` crate.
+    #[cfg(feature = "palette_support")]
+    pub fn to_srgb(&mut self) -> Srgb {
+        Srgb::from(self)
+    }
+
+    /// Generates a random color and returns it as an `Srgba` struct from the `palette` crate.
+    #[cfg(feature = "palette_support")]
+    pub fn to_srgba(&mut self) -> Srgba {
+        Srgba::from(self)
+    }
+
+    /* `ecolor` crate support` */
+
+    /// Generates a random color and returns it as an `Color32` struct from the `ecolor` crate.
+    ///
+    /// `Color32` stores premultiplied alpha: the RGB channels are converted to
+    /// linear light, multiplied by the alpha value and converted back. Use
+    /// `to_rgba_array` for straight alpha.
+    #[cfg(feature = "ecolor_support")]
+    pub fn to_color32(&mut self) -> Color32 {
+        Color32::from(self)
+    }
+}
+
+impl Default for RandomColor {
+    fn default() -> Self {
+        RandomColor::new()
+    }
+}
+
+/// Displays the next color the generator would produce, in its default
+/// format, without advancing the generator.
+impl fmt::Display for RandomColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut generator = self.clone();
+
+        write!(f, "{}", generator.to_css(self.default_format))
+    }
+}
+
+#[cfg(feature = "rgb_support")]
+impl From<RandomColor> for Rgb<u8> {
+    fn from(value: RandomColor) -> Self {
+        let rgb = value.into_rgb_array();
+
+        Rgb {
+            r: rgb[0],
+            g: rgb[1],
+            b: rgb[2],
+        }
+    }
+}
+
+#[cfg(feature = "rgb_support")]
+impl From<&mut RandomColor> for Rgb<u8> {
+    fn from(value: &mut RandomColor) -> Self {
+        let rgb = value.to_rgb_array();
+
+        Rgb {
+            r: rgb[0],
+            g: rgb[1],
+            b: rgb[2],
+        }
+    }
+}
+
+#[cfg(feature = "rgb_support")]
+impl From<RandomColor> for rgb::Rgba<u8> {
+    fn from(value: RandomColor) -> Self {
+        let rgba = value.into_rgba_array();
+
+        rgb::Rgba {
+            r: rgba[0],
+            g: rgba[1],
+            b: rgba[2],
+            a: rgba[3],
+        }
+    }
+}
+
+#[cfg(feature = "rgb_support")]
+impl From<&mut RandomColor> for rgb::Rgba<u8> {
+    fn from(value: &mut RandomColor) -> Self {
+        let rgba = value.to_rgba_array();
+
+        rgb::Rgba {
+            r: rgba[0],
+            g: rgba[1],
+            b: rgba[2],
+            a: rgba[3],
+        }
+    }
+}
+
+#[cfg(feature = "palette_support")]
+impl From<RandomColor> for Srgb {
+    fn from(value: RandomColor) -> Self {
+        let rgb = value.into_f32_rgb_array();
+
+        Srgb::new(rgb[0], rgb[1], rgb[2])
+    }
+}
+
+#[cfg(feature = "palette_support")]
+impl From<&mut RandomColor> for Srgb {
+    fn from(value: &mut RandomColor) -> Self {
+        let rgb = value.to_f32_rgb_array();
+
+        Srgb::new(rgb[0], rgb[1], rgb[2])
+    }
+}
+
+#[cfg(feature = "palette_support")]
+impl From<RandomColor> for Srgba {
+    fn from(value: RandomColor) -> Self {
+        let rgba = value.into_f32_rgba_array();
+
+        Srgba::new(rgba[0], rgba[1], rgba[2], rgba[3])
+    }
+}
+
+#[cfg(feature = "palette_support")]
+impl From<&mut RandomColor> for Srgba {
+    fn from(value: &mut RandomColor) -> Self {
+        let rgba = value.to_f32_rgba_array();
+
+        Srgba::new(rgba[0], rgba[1], rgba[2], rgba[3])
+    }
+}
+
+/// Converts into a `Color32`, premultiplying the RGB channels by the alpha
+/// value in linear light.
+#[cfg(feature = "ecolor_support")]
+impl From<RandomColor> for Color32 {
+    fn from(value: RandomColor) -> Self {
+        let rgba = value.into_rgba_array();
+        Color32::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3])
+    }
+}
+
+/// Converts into a `Color32`, premultiplying the RGB channels by the alpha
+/// value in linear light.
+#[cfg(feature = "ecolor_support")]
+impl From<&mut RandomColor> for Color32 {
+    fn from(value: &mut RandomColor) -> Self {
+        let rgba = value.to_rgba_array();
+        Color32::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3])
+    }
+}
+
+/// Converts into an `Rgba`, premultiplying the RGB channels by the alpha
+/// value, like `RandomColor::to_premultiplied_f32_rgba_array`.
+#[cfg(feature = "ecolor_support")]
+impl From<RandomColor> for Rgba {
+    fn from(value: RandomColor) -> Self {
+        let rgba = value.into_f32_rgba_array();
+        Rgba::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3])
+    }
+}
+
+/// Converts into an `Rgba`, premultiplying the RGB channels by the alpha
+/// value, like `RandomColor::to_premultiplied_f32_rgba_array`.
+#[cfg(feature = "ecolor_support")]
+impl From<&mut RandomColor> for Rgba {
+    fn from(value: &mut RandomColor) -> Self {
+        let rgba = value.to_f32_rgba_array();
+        Rgba::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assigner::{ColorAssigner, MappingParseError};
+    use color::{DeltaEMethod, MixMode, Mood, Temperature};
+    use color_dictionary::ColorInformation;
+    use color_palette::Pattern;
+    use session::Session;
+
+    #[test]
+    fn generates_different_colors_using_the_same_instance() {
+        let mut rc = RandomColor::new();
+
+        assert_ne!(rc.to_rgb_string(), rc.to_rgb_string());
+    }
+
+    #[test]
+    fn generates_color_as_hsv_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hsv_array();
+
+        assert_eq!(test_case, [191, 30, 98]);
+    }
+
+    #[test]
+    fn generates_color_as_rgb_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_rgb_string();
+
+        assert_eq!(test_case, "rgb(174, 236, 249)");
+    }
+
+    #[test]
+    fn generates_color_as_rgba_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_rgba_string();
+
+        assert_eq!(test_case, "rgba(174, 236, 249, 1)");
+    }
+
+    #[test]
+    fn generates_color_as_modern_rgb_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(0.5)
+            .to_modern_rgb_string();
+
+        assert_eq!(test_case, "rgb(174 236 249 / 0.5)");
+    }
+
+    #[test]
+    fn generates_color_as_modern_hsl_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(0.5)
+            .to_modern_hsl_string();
+
+        assert_eq!(test_case, "hsl(191 88.0% 83.3% / 0.5)");
+    }
+
+    #[test]
+    fn generates_color_as_rgb_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_rgb_array();
+
+        assert_eq!(test_case, [174, 236, 249]);
+    }
+
+    #[test]
+    fn generates_color_as_f32_rgb_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_f32_rgb_array();
+
+        assert_eq!(test_case, [0.68235296, 0.9254902, 0.9764706]);
+    }
+
+    #[test]
+    fn generates_color_as_rgb16_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_rgb16_array();
+
+        assert_eq!(test_case, [44957, 60692, 64224]);
+    }
+
+    #[test]
+    fn generates_color_as_f32_rgba_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_f32_rgba_array();
+
+        assert_eq!(test_case, [0.68235296, 0.9254902, 0.9764706, 1.0]);
+    }
+
+    #[test]
+    fn generates_color_as_premultiplied_rgba_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(0.5)
+            .to_premultiplied_rgba_array();
+
+        assert_eq!(test_case, [87, 118, 124, 127]);
+    }
+
+    #[test]
+    fn generates_color_as_premultiplied_f32_rgba_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(0.5)
+            .to_premultiplied_f32_rgba_array();
+
+        assert_eq!(
+            test_case,
+            [174.0 / 510.0, 236.0 / 510.0, 249.0 / 510.0, 0.5]
+        );
+    }
+
+    #[test]
+    fn generates_color_as_linear_rgb_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_linear_rgb_array();
+
+        assert_eq!(test_case, [0.42326775, 0.8387991, 0.9473066]);
+    }
+
+    #[test]
+    fn generates_color_as_linear_rgba_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(0.5)
+            .to_linear_rgba_array();
+
+        assert_eq!(test_case, [0.42326775, 0.8387991, 0.9473066, 0.5]);
+    }
+
+    #[test]
+    fn generates_color_as_hsl_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hsl_string();
+
+        assert_eq!(test_case, "hsl(191, 88.0%, 83.3%)");
+    }
+
+    #[test]
+    fn generates_color_as_hsla_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hsla_string();
+
+        assert_eq!(test_case, "hsl(191, 88.0%, 83.3%, 1)");
+    }
+
+    #[test]
+    fn generates_color_as_hsl_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hsl_array();
+
+        assert_eq!(test_case, [191, 88, 83]);
+    }
+
+    #[test]
+    fn generates_color_as_hsv_f32_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hsv_f32_array();
+
+        assert_eq!(test_case, [191.0, 0.3, 0.98]);
+
+        let red = RandomColor::new()
+            .hue(Gamut::Red)
+            .seed(0)
+            .to_hsv_f32_array();
+
+        assert_eq!(red, [353.0, 0.55, 0.99]);
+    }
+
+    #[test]
+    fn maps_colors_into_gamut() {
+        let (sin, cos) = 150f32.to_radians().sin_cos();
+        let green = conversions::oklab_to_rgb([0.7, 0.35 * cos, 0.35 * sin]);
+        let oklch = |[r, g, b]: [u8; 3]| {
+            conversions::lab_to_lch(conversions::rgb_to_oklab(
+                [r, g, b].map(|c| c as f32 / 255.0),
+            ))
+        };
+
+        let reduced = oklch(GamutMapping::ReduceChroma.map(green));
+        assert!((reduced[0] - 0.7).abs() < 0.01);
+        assert!((reduced[2] - 150.0).abs() < 1.0);
+
+        let css4 = oklch(GamutMapping::Css4.map(green));
+        assert!((css4[0] - 0.7).abs() < 0.02);
+        assert!((css4[2] - 150.0).abs() < 5.0);
+        assert!(css4[1] > reduced[1] && css4[1] < 0.35);
+
+        let in_gamut = [0.2, 0.5, 0.8];
+        assert_eq!(
+            GamutMapping::Css4.map(in_gamut),
+            GamutMapping::Clip.map(in_gamut)
+        );
+        assert_eq!(GamutMapping::Clip.map([1.2, -0.3, 0.5]), [255, 0, 128]);
+
+        let colormap = RandomColor::new()
+            .seed(42)
+            .gamut_mapping(GamutMapping::Css4)
+            .to_colormap(Colormap::Viridis, 5);
+        assert_eq!(colormap.len(), 5);
+    }
+
+    #[test]
+    fn maps_mixes_and_value_maps_into_gamut() {
+        let red = Color::new(255, 0, 0, 255);
+        let yellow = Color::new(255, 255, 0, 255);
+
+        let clipped = red.mix(&yellow, 0.5, MixMode::Oklab);
+        let reduced = red.mix_mapped(&yellow, 0.5, MixMode::Oklab, GamutMapping::ReduceChroma);
+        assert_eq!(
+            clipped,
+            red.mix_mapped(&yellow, 0.5, MixMode::Oklab, GamutMapping::Clip)
+        );
+        assert_ne!(clipped, reduced);
+
+        let mut mapper = ValueMapper::new(&[(0.0, red), (1.0, yellow)]);
+        assert_eq!(mapper.map(0.5), Some(clipped));
+        mapper.gamut_mapping(GamutMapping::ReduceChroma);
+        assert_eq!(mapper.map(0.5), Some(reduced));
+    }
+
+    #[test]
+    fn sets_alpha() {
+        let mut random_color = RandomColor::new();
+
+        random_color.alpha(0.5).alpha(1.0);
+        assert_eq!(random_color.alpha, Some(1.0));
+
+        random_color.alpha(-2.0);
+        assert_eq!(random_color.alpha, Some(0.0));
+
+        random_color.alpha(f32::NAN);
+        assert_eq!(random_color.alpha, Some(0.0));
+
+        assert_eq!(
+            random_color.try_alpha(1.5).err(),
+            Some(AlphaError::OutOfRange(1.5))
+        );
+        assert_eq!(
+            random_color.try_alpha(f32::NAN).err(),
+            Some(AlphaError::NotANumber)
+        );
+        assert_eq!(random_color.alpha, Some(0.0));
+
+        let color = random_color.try_alpha(0.5).unwrap().seed(42).to_color();
+        assert_eq!(color.a, 127);
+    }
+
+    #[test]
+    fn mixes_colors() {
+        let blue = Color::new(30, 90, 230, 255);
+        let yellow = Color::new(250, 220, 30, 0);
+
+        assert_eq!(blue.mix(&yellow, 0.0, MixMode::Linear), blue);
+        assert_eq!(blue.mix(&yellow, 2.0, MixMode::Oklab), yellow);
+        assert_eq!(blue.mix(&yellow, 0.5, MixMode::Linear).a, 128);
+        assert_eq!(
+            blue.mix(&blue, 0.5, MixMode::Oklab).to_rgb_array(),
+            blue.to_rgb_array()
+        );
+        assert_eq!(
+            blue.mix(&yellow, 0.5, MixMode::Linear).temperature(),
+            Temperature::Neutral
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "pigment_mixing")]
+    fn mixes_colors_as_pigments() {
+        let blue = Color::new(30, 90, 230, 255);
+        let yellow = Color::new(250, 220, 30, 255);
+
+        let [h, s, _] = blue.mix(&yellow, 0.5, MixMode::Pigment).to_hsv_array();
+
+        assert!((90..=150).contains(&h));
+        assert!(s > 50);
+        assert_eq!(blue.mix(&yellow, 0.0, MixMode::Pigment), blue);
+        assert_eq!(blue.mix(&yellow, 1.0, MixMode::Pigment), yellow);
+    }
+
+    #[test]
+    fn generates_color_as_munsell_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_munsell_string();
+
+        assert_eq!(test_case, "10BG 9.0/4");
+
+        let gray = RandomColor::new()
+            .saturation(0)
+            .brightness(50)
+            .to_munsell_string();
+
+        assert_eq!(gray, "N 5.3/");
+        assert_eq!(
+            conversions::lch_to_munsell([40.0, 60.0, 20.0]),
+            [5.0, 4.0, 12.0]
+        );
+        assert_eq!(
+            conversions::lch_to_munsell([40.0, 60.0, 10.0])[0].round(),
+            2.0
+        );
+    }
+
+    #[test]
+    fn generates_color_as_hsl_f32_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hsl_f32_array();
+
+        assert_eq!(
+            test_case.map(|c| (c * 10.0).round() / 10.0),
+            [191.0, 88.0, 83.3]
+        );
+
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Red);
+
+        assert_eq!(
+            rc.seed(0)
+                .to_hsl_f32_array()
+                .map(|c| (c * 10.0).round() / 10.0),
+            [353.0, 96.5, 71.8]
+        );
+        assert_eq!(rc.seed(0).to_hsl_string(), "hsl(353, 96.5%, 71.8%)");
+        assert_eq!(rc.seed(0).to_hsl_array(), [353, 96, 71]);
+    }
+
+    #[test]
+    fn generates_color_as_hwb_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hwb_array();
+
+        assert_eq!(test_case, [191, 69, 2]);
+    }
+
+    #[test]
+    fn generates_color_as_hwb_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hwb_string();
+
+        assert_eq!(test_case, "hwb(191 69% 2%)");
+    }
+
+    #[test]
+    fn generates_red_color_as_hwb() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Red);
+
+        assert_eq!(rc.seed(0).to_hwb_array(), [353, 45, 1]);
+        assert_eq!(rc.seed(0).to_hwb_string(), "hwb(353 45% 1%)");
+    }
+
+    #[test]
+    fn generates_color_as_cmyk_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_cmyk_array();
+
+        assert_eq!(test_case, [0.3012048, 0.052208833, 0.0, 0.02352941]);
+    }
+
+    #[test]
+    fn generates_color_as_cmyk_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_cmyk_string();
+
+        assert_eq!(test_case, "cmyk(30%, 5%, 0%, 2%)");
+    }
+
+    #[test]
+    fn generates_color_as_xyz_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_xyz_array();
+
+        assert_eq!(test_case, [0.6493782, 0.7607894, 1.0160482]);
+    }
+
+    #[test]
+    fn generates_color_adapted_to_d50() {
+        let mut white = RandomColor::new();
+        white.saturation(0).brightness(100);
+
+        let xyz = white.to_xyz_d50_array();
+        let [l, a, b] = white.to_lab_d50_array();
+
+        assert!(xyz
+            .iter()
+            .zip([0.96422, 1.0, 0.82521])
+            .all(|(c, w)| (c - w).abs() < 0.001));
+        assert!((l - 100.0).abs() < 0.1 && a.abs() < 0.1 && b.abs() < 0.1);
+
+        let [l, _, _] = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .to_lab_d50_array();
+
+        assert!((l - 89.64586).abs() < 0.001);
+    }
+
+    #[test]
+    fn generates_color_as_lab_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_lab_array();
+
+        assert_eq!(test_case, [90, -16, -13]);
+    }
+
+    #[test]
+    fn generates_color_as_f32_lab_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_f32_lab_array();
+
+        assert_eq!(test_case, [89.89598, -16.07275, -12.8612995]);
+    }
+
+    #[test]
+    fn generates_color_as_lch_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_lch_array();
+
+        assert_eq!(test_case, [89.89598, 20.5851, 218.66653]);
+    }
+
+    #[test]
+    fn generates_color_as_lch_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_lch_string();
+
+        assert_eq!(test_case, "lch(89.90% 20.59 218.67)");
+    }
+
+    #[test]
+    fn generates_color_as_hsluv_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hsluv_array();
+
+        assert_eq!(test_case, [210.3278, 81.904785, 89.89598]);
+    }
+
+    #[test]
+    fn generates_color_as_oklch_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_oklch_array();
+
+        assert_eq!(test_case, [0.9067033, 0.06437216, 213.06903]);
+    }
+
+    #[test]
+    fn generates_color_as_oklch_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_oklch_string();
+
+        assert_eq!(test_case, "oklch(90.67% 0.0644 213.07)");
+    }
+
+    #[test]
+    fn generates_color_as_display_p3_string() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_display_p3_string();
+
+        assert_eq!(test_case, "color(display-p3 0.7363 0.9194 0.9720 / 1)");
+    }
+
+    #[test]
+    fn generates_color_as_rec2020_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_rec2020_array();
+
+        assert_eq!(test_case, [0.7654982, 0.9021549, 0.9678345]);
+    }
+
+    #[test]
+    fn generates_color_as_hex() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hex();
+
+        assert_eq!(test_case, "#aeecf9");
+    }
+
+    #[test]
+    fn generates_color_as_short_hex() {
+        let mut random_color = RandomColor::new();
+        random_color
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .alpha(1.0);
+
+        assert_eq!(random_color.seed(42).to_short_hex(false), None);
+        assert_eq!(
+            random_color.seed(42).to_short_hex(true),
+            Some("#aef".to_string())
+        );
+
+        random_color.on_candidate(|_| Candidate::Adjust(0, 0, 100));
+
+        assert_eq!(random_color.to_short_hex(false), Some("#fff".to_string()));
+    }
+
+    #[test]
+    fn generates_color_as_css() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Blue).luminosity(Luminosity::Light).alpha(1.0);
+
+        assert_eq!(rc.seed(42).to_css(CssFormat::Hex), "#aeecf9");
+        assert_eq!(
+            rc.seed(42).to_css(CssFormat::Rgb),
+            rc.seed(42).to_rgb_string()
+        );
+        assert_eq!(
+            rc.seed(42).to_css(CssFormat::Oklch),
+            rc.seed(42).to_oklch_string()
+        );
+    }
+
+    #[test]
+    fn displays_color_in_default_format() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0);
+
+        assert_eq!(rc.to_string(), "#aeecf9");
+        assert_eq!(
+            rc.default_format(CssFormat::Rgb).to_string(),
+            rc.clone().to_rgb_string()
+        );
+        assert_eq!(rc.to_hex(), "#aeecf9");
+    }
+
+    #[test]
+    fn generates_color_as_web_safe_hex() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_web_safe_hex();
+
+        assert_eq!(test_case, "#99ffff");
+    }
+
+    #[test]
+    fn generates_color_as_hex_with_alpha() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(0.5)
+            .to_hex_with_alpha();
+
+        assert_eq!(test_case, "#aeecf97f");
+    }
+
+    #[test]
+    fn to_hex_is_rrggbb() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hex();
+        let [r, g, b] = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_rgb_array();
+
+        assert_eq!(test_case, format!("#{:02x}{:02x}{:02x}", r, g, b).as_str());
+    }
+
+    #[test]
+    fn single_digit_hex_are_padded_by_to_two_chars() {
+        let test_case = RandomColor::new()
+            .luminosity(Luminosity::Dark)
+            .seed(5)
+            .to_hex();
+
+        assert_eq!(test_case, "#3e0496");
+    }
+
+    #[test]
+    fn generates_color_as_rgb565() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_rgb565();
+
+        assert_eq!(test_case, 44927);
+    }
+
+    #[test]
+    fn generates_color_luma() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Blue).luminosity(Luminosity::Light).alpha(1.0);
+
+        assert_eq!(rc.seed(42).to_luma(), 224);
+        assert!((rc.seed(42).to_f32_luma() - 0.8775).abs() < 0.001);
+    }
+
+    #[test]
+    fn generates_color_relative_luminance() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .relative_luminance();
+
+        assert!((test_case - 0.7576).abs() < 0.001);
+    }
+
+    #[test]
+    fn picks_readable_text_color() {
+        let black = Color::new(0, 0, 0, 255);
+        let white = Color::new(255, 255, 255, 255);
+
+        assert_eq!(Color::new(174, 236, 249, 255).readable_text_color(), black);
+        assert_eq!(Color::new(20, 40, 90, 255).readable_text_color(), white);
+    }
+
+    #[test]
+    fn generates_color_as_ansi256() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_ansi256();
+
+        assert_eq!(test_case, 159);
+
+        let gray = RandomColor::new().seed(42).force_grayscale().to_ansi256();
+
+        assert!(gray >= 232 || [16, 59, 102, 145, 188, 231].contains(&gray));
+    }
+
+    #[test]
+    fn generates_color_as_ws2812_grb() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_ws2812_grb();
+
+        assert_eq!(test_case, [205, 87, 239]);
+    }
+
+    #[test]
+    fn generates_color_as_hue_hsb() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_hue_hsb();
+
+        assert_eq!(test_case, [34770, 76, 249]);
+
+        let red = RandomColor::new().hue(Gamut::Red).seed(0).to_hue_hsb();
+
+        assert_eq!(red, [64261, 140, 251]);
+    }
+
+    #[test]
+    fn generates_color_as_dmx() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_dmx(0.5);
+
+        assert_eq!(test_case, [87, 118, 125]);
+    }
+
+    #[test]
+    fn generates_color_as_dmx_rgbw() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_dmx_rgbw(1.0);
+
+        assert_eq!(test_case, [0, 62, 75, 174]);
+    }
+
+    #[test]
+    fn generates_color_as_rgbw_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_rgbw_array(WhiteExtraction::MinChannel);
+
+        assert_eq!(test_case, [0, 62, 75, 174]);
+    }
+
+    #[test]
+    fn generates_color_as_luminance_based_rgbw_array() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_rgbw_array(WhiteExtraction::Luminance);
+
+        assert_eq!(test_case, [18, 80, 93, 156]);
+    }
+
+    #[test]
+    fn generates_color_as_ycbcr_array() {
+        let mut random_color = RandomColor::new();
+        random_color
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .alpha(1.0);
+
+        assert_eq!(
+            random_color.seed(42).to_ycbcr_array(YCbCrStandard::Bt709),
+            [208, 140, 100]
+        );
+        assert_eq!(
+            random_color.seed(42).to_ycbcr_array(YCbCrStandard::Bt601),
+            [204, 143, 100]
+        );
+    }
+
+    #[test]
+    fn generates_color_as_color_mix_string() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0);
+
+        let test_case = rc.clone().to_color_mix_string(60);
+        let first = rc.to_hex();
+        let second = rc.to_hex();
+
+        assert_eq!(
+            test_case,
+            format!("color-mix(in oklab, {} 60%, {})", first, second)
+        );
+    }
+
+    #[test]
+    fn generates_color_as_color() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_color();
+
+        assert_eq!(test_case, Color::new(174, 236, 249, 255));
+    }
+
+    #[test]
+    fn parses_color_from_hex() {
+        assert_eq!(
+            Color::from_hex("#336699"),
+            Ok(Color::new(51, 102, 153, 255))
+        );
+        assert_eq!(Color::from_hex("369"), Ok(Color::new(51, 102, 153, 255)));
+        assert_eq!(
+            Color::from_hex("#33669980"),
+            Ok(Color::new(51, 102, 153, 128))
+        );
+        assert_eq!(Color::from_hex("#3366"), Ok(Color::new(51, 51, 102, 102)));
+        assert_eq!(
+            Color::from_hex("#33669"),
+            Err(HexParseError::InvalidLength(5))
+        );
+        assert_eq!(
+            Color::from_hex("#33669g"),
+            Err(HexParseError::InvalidDigit('g'))
+        );
+    }
+
+    #[test]
+    fn formats_color_as_hex() {
+        let color = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .to_color();
+
+        assert_eq!(format!("{:x}", color), "aeecf9");
+        assert_eq!(format!("{:X}", color), "AEECF9");
+        assert_eq!(format!("{:#x}", color), color.to_hex());
+        assert_eq!(format!("{:#X}", color), "#AEECF9");
+    }
+
+    #[test]
+    fn round_trips_canonical_string() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Blue).luminosity(Luminosity::Light).seed(42);
+
+        let canonical = rc.to_canonical_string().unwrap();
+        let (mut restored, color) = RandomColor::from_canonical_string(&canonical).unwrap();
+
+        assert!(canonical.starts_with("hsv:seed="));
+        assert!(canonical.contains(";hue=blue;luminosity=light;a=1;version=v1;"));
+        assert!((170..260).contains(&color.to_hsv_array()[0]));
+        assert_eq!(restored.to_hex(), rc.to_hex());
+
+        rc.random_alpha();
+        let canonical = rc.to_canonical_string().unwrap();
+        let (mut restored, _) = RandomColor::from_canonical_string(&canonical).unwrap();
+
+        assert!(canonical.contains(";a=random;"));
+        assert_eq!(restored.to_rgba_array(), rc.to_rgba_array());
+    }
+
+    #[test]
+    fn round_trips_every_setting_in_canonical_string() {
+        let mut mask = [false; 360];
+        mask[150..250].fill(true);
+        let mut dictionary = ColorDictionary::new();
+        dictionary.blue = ColorInformation::new([179, 257], vec![[10, 100], [100, 60]]);
+
+        let mut rc = RandomColor::new();
+        rc.seed(1)
+            .saturation(10..=20)
+            .brightness(60..=90)
+            .saturation_bias(Curve::Power(2.5))
+            .value_bias(Curve::EaseOut)
+            .luminosity_blend(Luminosity::Light, Luminosity::Dark, 0.25)
+            .style(Style::Neon)
+            .hue_mask(&mask)
+            .rotate_gamuts(&[Gamut::Blue, Gamut::Green])
+            .min_lightness_gap(12.5)
+            .channel_jitter(10, 0, 5)
+            .retry_policy(RetryPolicy {
+                max_attempts: 8,
+                on_exhausted: Fallback::Color(Color::new(1, 2, 3, 4)),
+            })
+            .sampling(Sampling::Edge)
+            .default_format(CssFormat::Oklch)
+            .gamut_mapping(GamutMapping::Css4)
+            .algorithm_version(AlgorithmVersion::V2)
+            .alpha(0.5);
+        rc.color_dictionary = dictionary;
+        rc.to_color();
+
+        let canonical = rc.to_canonical_string().unwrap();
+        let (mut restored, _) = RandomColor::from_canonical_string(&canonical).unwrap();
+
+        assert!(canonical.contains(";saturation=10-20;brightness=60-90;"));
+        assert!(canonical.contains(";rotation=blue,green@1;"));
+        assert_eq!(restored.hue_mask, rc.hue_mask);
+        assert_eq!(restored.color_dictionary, rc.color_dictionary);
+        assert_eq!(restored.default_format, CssFormat::Oklch);
+        assert_eq!(restored.retry_policy, rc.retry_policy);
+        assert_eq!(restored.to_palette(5), rc.to_palette(5));
+
+        let mut rc = RandomColor::new();
+        rc.seed(2)
+            .weights_from_counts(&[(Gamut::Red, 3), (Gamut::Pink, 1)])
+            .near(Color::new(200, 40, 40, 255), 8)
+            .exact_hue(10)
+            .force_grayscale();
+
+        let canonical = rc.to_canonical_string().unwrap();
+        let (mut restored, _) = RandomColor::from_canonical_string(&canonical).unwrap();
+
+        assert_eq!(restored.gamut_weights, rc.gamut_weights);
+        assert_eq!(restored.to_hex(), rc.to_hex());
+
+        rc.on_candidate(|_| Candidate::Accept);
+
+        assert_eq!(rc.to_canonical_string(), None);
+    }
+
+    #[test]
+    fn rejects_invalid_canonical_strings() {
+        assert_eq!(
+            RandomColor::from_canonical_string("okhsv:seed=1").unwrap_err(),
+            CanonicalParseError::InvalidPrefix
+        );
+        assert_eq!(
+            RandomColor::from_canonical_string("hsv:seed=1;a=1;version=v1;h=0;s=0").unwrap_err(),
+            CanonicalParseError::MissingField("v")
+        );
+        assert_eq!(
+            RandomColor::from_canonical_string("hsv:seed=1;hue=teal").unwrap_err(),
+            CanonicalParseError::InvalidField("hue".to_string())
+        );
+        assert_eq!(
+            RandomColor::from_canonical_string("hsv:seed=1;a=1;version=v1;h=0;s=0;v=0")
+                .unwrap_err(),
+            CanonicalParseError::Mismatch
+        );
+    }
+
+    #[test]
+    fn computes_delta_e_with_other_formulas() {
+        let reference = [50.0, 2.6772, -79.7751];
+        let sample = [50.0, 0.0, -82.7485];
+
+        assert!((difference::cie76(reference, sample) - 4.0011).abs() < 0.001);
+        assert!((difference::cie94(reference, sample, 1.0, 0.045, 0.015) - 1.3950).abs() < 0.001);
+        assert!((difference::cmc(reference, sample, 1.0, 1.0) - 1.7387).abs() < 0.001);
+
+        let a = Color::new(174, 236, 249, 255);
+        let b = Color::new(160, 230, 250, 255);
+
+        assert_eq!(a.delta_e_with(&b, DeltaEMethod::default()), a.delta_e(&b));
+        assert_eq!(a.delta_e_with(&a, DeltaEMethod::Cie94Textiles), 0.0);
+        assert!(
+            a.delta_e_with(&b, DeltaEMethod::Cie76)
+                > a.delta_e_with(&b, DeltaEMethod::Cie94GraphicArts)
+        );
+        assert!(
+            a.delta_e_with(
+                &b,
+                DeltaEMethod::Cmc {
+                    lightness: 2.0,
+                    chroma: 1.0
+                }
+            ) > 0.0
+        );
+    }
+
+    #[test]
+    fn reads_dominant_wavelength_and_purity() {
+        let red = Color::new(255, 0, 0, 255);
+        let green = Color::new(0, 255, 0, 255);
+        let blue = Color::new(0, 0, 255, 255);
+        let magenta = Color::new(255, 0, 255, 255);
+        let gray = Color::new(128, 128, 128, 255);
+
+        assert!((red.dominant_wavelength().unwrap() - 611.0).abs() < 2.0);
+        assert!((green.dominant_wavelength().unwrap() - 549.0).abs() < 2.0);
+        assert!((blue.dominant_wavelength().unwrap() - 464.0).abs() < 2.0);
+        assert!(magenta.dominant_wavelength().unwrap() < 0.0);
+        assert_eq!(gray.dominant_wavelength(), None);
+        assert_eq!(gray.excitation_purity(), 0.0);
+        assert!(red.excitation_purity() > Color::new(255, 128, 128, 255).excitation_purity());
+        assert!(red.excitation_purity() <= 1.0);
+    }
+
+    #[test]
+    fn generates_reproducible_colors_near_hex() {
+        let mut a = RandomColor::seeded_near_hex("#336699", 10).unwrap();
+        let mut b = RandomColor::seeded_near_hex("#336699", 10).unwrap();
+
+        for _ in 0..20 {
+            let color = a.to_color();
+            let [h, s, v] = color.to_hsv_array();
+
+            assert_eq!(color, b.to_color());
+            assert!((200..=220).contains(&h));
+            assert!((57..=77).contains(&s));
+            assert!((50..=70).contains(&v));
+        }
+    }
+
+    #[test]
+    fn computes_ciede2000_difference() {
+        let test_case = difference::ciede2000([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485]);
+
+        assert!((test_case - 2.0425).abs() < 1e-4);
+    }
+
+    #[test]
+    fn compares_colors_perceptually() {
+        let color = Color::new(174, 236, 249, 255);
+
+        assert!(color.approx_eq(&Color::new(175, 236, 248, 255), 1.0));
+        assert!(!color.approx_eq(&Color::new(62, 4, 150, 255), 1.0));
+        assert_eq!(color.delta_e(&color), 0.0);
+    }
+
+    #[test]
+    fn classifies_color_temperature() {
+        assert_eq!(
+            Color::new(174, 236, 249, 255).temperature(),
+            Temperature::Cool
+        );
+        assert_eq!(
+            Color::new(249, 140, 20, 255).temperature(),
+            Temperature::Warm
+        );
+        assert_eq!(
+            Color::new(230, 40, 160, 255).temperature(),
+            Temperature::Warm
+        );
+        assert_eq!(
+            Color::new(128, 128, 130, 255).temperature(),
+            Temperature::Neutral
+        );
+
+        let purple = Color::new(155, 41, 204, 255);
+        let pink = Color::new(158, 41, 204, 255);
+
+        assert_eq!(purple.to_hsv_array()[0], 282);
+        assert_eq!(purple.temperature(), Temperature::Cool);
+        assert_eq!(pink.to_hsv_array()[0], 283);
+        assert_eq!(pink.temperature(), Temperature::Warm);
+    }
+
+    #[test]
+    fn generates_colors_from_weighted_gamuts() {
+        let mut rc = RandomColor::new();
+        rc.gamut_weights(&[(Gamut::Blue, 1), (Gamut::Red, 0)])
+            .seed(42);
+
+        for _ in 0..20 {
+            assert!((179..=257).contains(&rc.to_hsv_array()[0]));
+        }
+    }
+
+    #[test]
+    fn generates_colors_from_several_hues() {
+        let mut rc = RandomColor::new();
+        rc.hues(&[Gamut::Blue, Gamut::Pink]).seed(42);
+
+        assert_eq!(
+            rc.gamut_weights,
+            Some(vec![(Gamut::Blue, 78), (Gamut::Pink, 51)])
+        );
+
+        let hues: Vec<u32> = (0..100).map(|_| rc.to_hsv_array()[0]).collect();
+
+        assert!(hues
+            .iter()
+            .all(|h| (179..=257).contains(h) || (283..=334).contains(h)));
+        assert!(hues.iter().any(|h| *h <= 257) && hues.iter().any(|h| *h >= 283));
+    }
+
+    #[test]
+    fn generates_colors_with_exact_hue() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Green).exact_hue(-155).seed(42);
+
+        assert_eq!(rc.exact_hue, Some(205));
+
+        let [h, s, b] = rc.to_hsv_array();
+        let [_, s2, b2] = rc.to_hsv_array();
+
+        assert_eq!(h, 205);
+        assert_ne!((s, b), (s2, b2));
+
+        let mut mask = [false; 360];
+        mask[10] = true;
+        rc.exact_hue(200).hue_mask(&mask);
+
+        assert_eq!(rc.pick_hue(), 200);
+    }
+
+    #[test]
+    fn generates_colors_within_hue_mask() {
+        let mut mask = [false; 360];
+        mask[200..210].fill(true);
+        mask[10] = true;
+
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Blue).hue_mask(&mask).seed(42);
+
+        for _ in 0..50 {
+            assert!((200..210).contains(&rc.pick_hue()));
+        }
+
+        rc.hue(Gamut::Yellow);
+
+        for _ in 0..50 {
+            assert!(mask[rc.pick_hue() as usize]);
+        }
+    }
+
+    #[test]
+    fn generates_colors_within_saturation_setting() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Red).saturation(10..=25).seed(42);
+
+        for _ in 0..50 {
+            let [_, s, _] = rc.to_hsv_array();
+
+            assert!((10..=25).contains(&s));
+        }
+
+        assert_eq!(rc.saturation(40).to_hsv_array()[1], 40);
+        assert_eq!(
+            rc.saturation(90..=120).saturation,
+            Some(ValueRange::new(90, 100))
+        );
+    }
+
+    #[test]
+    fn generates_colors_within_brightness_setting() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Green)
+            .saturation(20..=40)
+            .brightness(30..=45)
+            .sampling(Sampling::Edge)
+            .seed(42);
+
+        for _ in 0..50 {
+            let [_, s, b] = rc.to_hsv_array();
+
+            assert!((20..=40).contains(&s));
+            assert!((30..=45).contains(&b));
+        }
+
+        assert_eq!(rc.brightness(100).to_hsv_array()[2], 100);
+    }
+
+    #[test]
+    fn biases_saturation_and_value_sampling() {
+        let mean = |rc: &mut RandomColor, component: usize| {
+            (0..200).map(|_| rc.to_hsv_array()[component]).sum::<u32>() / 200
+        };
+
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Blue).saturation(0..=100).brightness(0..=100);
+
+        let uniform = mean(rc.seed(42), 2);
+        let bright = mean(rc.seed(42).value_bias(Curve::EaseOut), 2);
+        let muted = mean(rc.seed(42).saturation_bias(Curve::Power(3.0)), 1);
+
+        assert!(bright > uniform + 10);
+        assert!(muted < 40);
+        assert_eq!(Curve::EaseInOut.apply(0.5), 0.5);
+    }
+
+    #[test]
+    fn generates_same_color_for_same_date() {
+        let a = RandomColor::for_date(2024, 12, 25).to_hex();
+        let b = RandomColor::for_date(2024, 12, 25).to_hex();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn generates_balanced_diff_pair() {
+        let (added, removed) = RandomColor::diff_pair(42);
+        let [added_h, added_s, added_v] = added.to_hsv_array();
+        let [removed_h, removed_s, removed_v] = removed.to_hsv_array();
+
+        assert_eq!(RandomColor::diff_pair(42), (added, removed));
+        assert!((63..=178).contains(&added_h));
+        assert!(removed_h <= 18 || removed_h >= 334);
+        assert!(added_s.abs_diff(removed_s) <= 1);
+        assert!(added_v.abs_diff(removed_v) <= 1);
+    }
+
+    #[test]
+    fn generates_same_color_for_combined_seeds() {
+        let a = RandomColor::new().seed_combined(&[3, 1, 2]).to_hex();
+        let b = RandomColor::new().seed_combined(&[1, 2, 3]).to_hex();
+        let c = RandomColor::new().seed_combined(&[1, 2, 3, 3]).to_hex();
+        let d = RandomColor::new().seed_combined(&["ana", "bo"]).to_hex();
+        let e = RandomColor::new().seed_combined(&["bo", "ana"]).to_hex();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(d, e);
+    }
+
+    #[test]
+    fn generates_same_color_within_time_bucket() {
+        let hour = Duration::from_secs(3600);
+        let start = UNIX_EPOCH + Duration::from_secs(1_700_000_000 / 3600 * 3600);
+
+        let a = RandomColor::for_time_bucket_at(start, hour).to_hex();
+        let b = RandomColor::for_time_bucket_at(start + Duration::from_secs(3599), hour).to_hex();
+        let c = RandomColor::for_time_bucket_at(start + hour, hour).to_hex();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn biases_date_colors_by_season() {
+        let weights = SeasonalWeights {
+            winter: vec![(Gamut::Blue, 1)],
+            ..Default::default()
+        };
+
+        for day in 1..=28 {
+            let [h, _, _] = RandomColor::for_date_with(2024, 2, day, &weights).to_hsv_array();
+
+            assert!((179..=257).contains(&h));
+        }
+    }
+
+    #[test]
+    fn infers_color_mood_tags() {
+        assert_eq!(Color::new(174, 236, 249, 255).mood_tags(), vec![Mood::Calm]);
+        assert_eq!(
+            Color::new(255, 60, 20, 255).mood_tags(),
+            vec![Mood::Energetic]
+        );
+        assert_eq!(
+            Color::new(90, 20, 110, 255).mood_tags(),
+            vec![Mood::Luxurious]
+        );
+        assert_eq!(
+            Color::new(140, 100, 50, 255).mood_tags(),
+            vec![Mood::Earthy]
+        );
+        assert_eq!(Color::new(50, 50, 55, 255).mood_tags(), vec![Mood::Serious]);
+    }
+
+    #[test]
+    fn fills_rgba_buffer() {
+        let mut buffer = [0u8; 10];
+        let count = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .fill_rgba(&mut buffer);
+
+        assert_eq!(count, 2);
+        assert_eq!(&buffer[0..4], &[174, 236, 249, 255]);
+        assert_eq!(&buffer[8..], &[0, 0]);
+    }
+
+    #[test]
+    fn fills_f32_rgba_buffer() {
+        let mut buffer = [0.0f32; 8];
+        let count = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha(1.0)
+            .fill_f32_rgba(&mut buffer);
+
+        assert_eq!(count, 2);
+        assert_eq!(&buffer[0..4], &[0.68235296, 0.9254902, 0.9764706, 1.0]);
+    }
+
+    #[test]
+    fn assigns_interleaved_vertex_colors() {
+        let mut rc = RandomColor::new();
+        rc.seed(42);
+
+        let data = rc
+            .clone()
+            .assign_vertex_colors(3, VertexLayout::Interleaved);
+
+        assert_eq!(data.len(), 12);
+        assert_eq!(&data[0..4], &rc.to_f32_rgba_array());
+        assert_eq!(&data[4..8], &rc.to_f32_rgba_array());
+    }
+
+    #[test]
+    fn assigns_planar_flat_face_colors() {
+        let mut rc = RandomColor::new();
+        rc.seed(42);
+
+        let data = rc
+            .clone()
+            .assign_flat_face_colors(6, 3, VertexLayout::Planar);
+        let first = rc.to_f32_rgba_array();
+        let second = rc.to_f32_rgba_array();
+
+        assert_eq!(data.len(), 24);
+        assert_eq!(
+            &data[0..6],
+            &[first[0], first[0], first[0], second[0], second[0], second[0]]
+        );
+        assert_eq!(data[18], first[3]);
+    }
+
+    #[test]
+    fn generates_distinct_palette() {
+        let palette = RandomColor::new().seed(42).to_distinct_palette(8, 10.0);
+
+        assert_eq!(palette.len(), 8);
+        for (i, a) in palette.iter().enumerate() {
+            for b in palette.iter().skip(i + 1) {
+                assert!(a.delta_e(b) >= 10.0);
+            }
+        }
+    }
+
+    #[test]
+    fn assigns_stable_colors_to_keys() {
+        let mut assigner = ColorAssigner::new(RandomColor::new().seed(42).clone(), 10.0);
+
+        let colors = assigner.colorize(&[1, 2, 1, 3, 2]);
+
+        assert_eq!(assigner.len(), 3);
+        assert_eq!(colors[0], colors[2]);
+        assert_eq!(colors[1], colors[4]);
+        assert!(colors[0].delta_e(&colors[1]) >= 10.0);
+    }
+
+    #[test]
+    fn assigns_distinct_calendar_colors() {
+        let existing = [
+            ("standup", Color::new(51, 102, 204, 255)),
+            ("review", Color::new(60, 180, 90, 255)),
+        ];
+        let mut calendar = ColorAssigner::for_calendar(
+            RandomColor::new().seed(42).clone(),
+            &[Gamut::Blue, Gamut::Green],
+            15.0,
+            &existing,
+        );
+
+        let color = calendar.get(&"retro");
+        let [h, _, _] = color.to_hsv_array();
+
+        assert_eq!(calendar.get(&"standup"), existing[0].1);
+        assert_eq!(calendar.get(&"retro"), color);
+        assert!((60..=260).contains(&h));
+        assert!(existing
+            .iter()
+            .all(|(_, existing)| color.delta_e(existing) >= 15.0));
+    }
+
+    #[test]
+    fn round_trips_assigner_mapping() {
+        let mut assigner = ColorAssigner::new(RandomColor::new().seed(42).clone(), 10.0);
+        assigner.colorize(&[7u32, 3, 5]);
+
+        let mapping = assigner.to_mapping_string();
+        let restored: ColorAssigner<u32> =
+            ColorAssigner::from_mapping_string(RandomColor::new(), 10.0, &mapping).unwrap();
+
+        assert_eq!(restored.to_mapping_string(), mapping);
+        assert_eq!(restored.assigned(&3), assigner.assigned(&3));
+        assert_eq!(
+            ColorAssigner::<u32>::from_mapping_string(RandomColor::new(), 10.0, "7#ffffff")
+                .unwrap_err(),
+            MappingParseError::MissingSeparator(1)
+        );
+    }
+
+    #[test]
+    fn undoes_and_redoes_session_colors() {
+        let mut session = Session::new(RandomColor::new().seed(42).clone());
+        let first = session.next_color();
+        let second = session.next_color();
+
+        assert_eq!(session.undo(), Some(second));
+        assert_eq!(session.current(), Some(first));
+        assert!(session.can_redo());
+        assert_eq!(session.redo(), Some(second));
+        assert_eq!(session.redo(), None);
+
+        session.undo();
+        assert_eq!(session.next_color(), second);
+        assert!(!session.can_redo());
+
+        session.undo();
+        session.undo();
+        assert_eq!(session.undo(), None);
+        assert!(session.history().is_empty());
+    }
+
+    #[test]
+    fn replays_session_with_new_settings() {
+        let mut session = Session::new(RandomColor::new().seed(42).clone());
+        let original = [session.next_color(), session.next_color()];
+
+        session.generator.force_grayscale();
+        let replayed = session.replay();
+
+        assert_eq!(replayed.len(), 2);
+        assert_ne!(replayed[0], original[0]);
+        assert!(replayed.iter().all(|c| c.r == c.g && c.g == c.b));
+        assert_eq!(session.history(), replayed);
+    }
+
+    #[test]
+    fn evicts_least_recently_used_keys() {
+        let red = Color::new(255, 0, 0, 255);
+        let mut assigner =
+            ColorAssigner::with_reserved(RandomColor::new().seed(42).clone(), 10.0, &[(0, red)]);
+        assigner.capacity(2);
+
+        let first = assigner.get(&1);
+        let second = assigner.get(&2);
+        let rng = assigner.generator.seed.clone();
+
+        assert_eq!(assigner.get(&1), first);
+        assert_eq!(assigner.generator.seed, rng);
+
+        assigner.get(&3);
+
+        assert_eq!(assigner.len(), 3);
+        assert_eq!(assigner.assigned(&0), Some(red));
+        assert_eq!(assigner.assigned(&1), Some(first));
+        assert_eq!(assigner.assigned(&2), None);
+
+        let keys: Vec<String> = assigner
+            .to_mapping_string()
+            .lines()
+            .map(|line| line.split('=').next().unwrap().to_string())
+            .collect();
+
+        assert_eq!(keys, ["0", "1", "3"]);
+        assert_ne!(assigner.get(&2), second);
+    }
+
+    #[test]
+    fn assigns_colors_avoiding_reserved_keys() {
+        let red = Color::new(255, 0, 0, 255);
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Red).seed(42);
+        let mut assigner = ColorAssigner::with_reserved(rc, 15.0, &[(0, red)]);
+
+        let colors = assigner.colorize(&[0, 1, 2]);
+
+        assert_eq!(colors[0], red);
+        assert!(colors[1].delta_e(&red) >= 15.0);
+        assert!(colors[2].delta_e(&red) >= 15.0);
+    }
+
+    #[test]
+    fn round_trips_lab_conversion() {
+        let rgb = [0.68235296, 0.9254902, 0.9764706];
+        let round_trip = conversions::lab_to_rgb(conversions::rgb_to_lab(rgb));
+
+        for c in 0..3 {
+            assert!((rgb[c] - round_trip[c]).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn generates_perturbed_colormap() {
+        let a = RandomColor::new()
+            .seed(1)
+            .to_colormap(Colormap::Viridis, 16);
+        let b = RandomColor::new()
+            .seed(2)
+            .to_colormap(Colormap::Viridis, 16);
+
+        assert_eq!(a.len(), 16);
+        assert_ne!(a, b);
+        for pair in a.colors.windows(2) {
+            let [l1, _, _] = pair[0].to_lab();
+            let [l2, _, _] = pair[1].to_lab();
+
+            assert!(l2 >= l1);
+        }
+    }
+
+    #[test]
+    fn equalizes_palette_lightness() {
+        let mut palette = RandomColor::new()
+            .hue(Gamut::Monochrome)
+            .seed(42)
+            .to_palette(5);
+
+        palette.equalize_lightness(20.0, 80.0);
+
+        let mut lightness: Vec<f32> = palette.iter().map(|c| c.to_lab()[0]).collect();
+        lightness.sort_by(f32::total_cmp);
+        for (i, l) in lightness.iter().enumerate() {
+            assert!((l - (20.0 + 15.0 * i as f32)).abs() < 1.0);
+        }
+    }
+
+    #[test]
+    fn generates_palette_with_min_lightness_gap() {
+        let palette = RandomColor::new()
+            .min_lightness_gap(15.0)
+            .seed(42)
+            .to_palette(5);
+
+        for (i, a) in palette.iter().enumerate() {
+            for b in palette.iter().skip(i + 1) {
+                assert!((a.to_lab()[0] - b.to_lab()[0]).abs() >= 15.0);
+            }
+        }
+    }
+
+    #[test]
+    fn suggests_patterns_in_lightness_order() {
+        let palette = Palette::new(vec![
+            Color::new(250, 250, 250, 255),
+            Color::new(10, 10, 10, 255),
+            Color::new(128, 128, 128, 255),
+        ]);
+
+        assert_eq!(
+            palette.patterns(),
+            vec![Pattern::Stripes, Pattern::Solid, Pattern::Dots]
+        );
+        assert_eq!(
+            palette.with_patterns()[1],
+            (Color::new(10, 10, 10, 255), Pattern::Solid)
+        );
+        assert_eq!(Pattern::CrossHatch as usize, 3);
+    }
+
+    #[test]
+    fn falls_back_when_retries_are_exhausted() {
+        let fallback = Color::new(1, 2, 3, 255);
+        let existing = [Color::new(0, 0, 0, 255)];
+
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .seed(42)
+            .retry_policy(RetryPolicy {
+                max_attempts: 4,
+                on_exhausted: Fallback::Color(fallback),
+            })
+            .to_distinct_color(&existing, 1000.0);
+
+        assert_eq!(test_case, fallback);
+    }
+
+    #[test]
+    fn namespaces_seeds() {
+        let calendar = RandomColor::new().seed(42).namespace("calendar").to_hex();
+        let chat = RandomColor::new().seed(42).namespace("chat").to_hex();
+        let chat_again = RandomColor::new().seed(42).namespace("chat").to_hex();
+
+        assert_ne!(calendar, chat);
+        assert_eq!(chat, chat_again);
+    }
+
+    #[test]
+    #[cfg(feature = "fast_hash")]
+    fn hashes_string_seeds_with_fnv() {
+        assert_eq!("".to_value(), 0xcbf29ce484222325);
+        assert_eq!("a".to_value(), 0xaf63dc4c8601ec8c);
+        assert_eq!("foobar".to_string().to_value(), 0x85944171f73967e8);
+    }
+
+    #[test]
+    fn generates_static_palette_source() {
+        let source = build_support::generate_static_palette(42, 2);
+        let palette = RandomColor::new().seed(42).to_palette(2);
+
+        assert_eq!(
+            source,
+            format!(
+                "pub const PALETTE: [[u8; 3]; 2] = [\n    [{}, {}, {}],\n    [{}, {}, {}],\n];\n",
+                palette.colors[0].r,
+                palette.colors[0].g,
+                palette.colors[0].b,
+                palette.colors[1].r,
+                palette.colors[1].g,
+                palette.colors[1].b
+            )
+        );
+    }
+
+    #[test]
+    fn diffs_palettes() {
+        let white = Color::new(255, 255, 255, 255);
+        let black = Color::new(0, 0, 0, 255);
+        let gray = Color::new(128, 128, 128, 255);
+        let before = Palette::new(vec![white, black, gray]);
+        let after = Palette::new(vec![white, gray]);
+
+        let diff = before.diff(&after);
+
+        assert_eq!(diff.added, vec![]);
+        assert_eq!(diff.removed, vec![gray]);
+        assert_eq!(diff.shifted.len(), 1);
+        assert_eq!(diff.shifted[0].index, 1);
+        assert_eq!(diff.max_delta_e(), black.delta_e(&gray));
+        assert!(before.diff(&before).is_empty());
+    }
+
+    #[test]
+    fn filters_candidates_with_callback() {
+        let mut rc = RandomColor::new();
+        rc.seed(42)
+            .on_candidate(|[h, _, _]| match (100..=200).contains(&h) {
+                true => Candidate::Reject,
+                false => Candidate::Accept,
+            });
+
+        for _ in 0..20 {
+            assert!(!(100..=200).contains(&rc.to_hsv_array()[0]));
+        }
+    }
+
+    #[test]
+    fn adjusts_candidates_with_callback() {
+        let test_case = RandomColor::new()
+            .seed(42)
+            .on_candidate(|[h, s, _]| Candidate::Adjust(h, s, 100))
+            .to_hsv_array();
+
+        assert_eq!(test_case[2], 100);
+    }
+
+    #[test]
+    fn generates_colors_with_custom_pick_strategy() {
+        struct Muted;
+
+        impl PickStrategy for Muted {
+            fn pick_saturation(&self, random_color: &mut RandomColor, _hue: i64) -> i64 {
+                random_color.seed.gen_range(5..=15)
+            }
+        }
+
+        let mut rc = RandomColor::new();
+        rc.seed(42).pick_strategy(Muted);
+
+        for _ in 0..20 {
+            assert!((5..=15).contains(&rc.to_hsv_array()[1]));
+        }
+    }
+
+    #[test]
+    fn default_pick_strategy_matches_default_behavior() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .pick_strategy(strategy::DefaultPickStrategy)
+            .to_hsv_array();
+
+        assert_eq!(test_case, [191, 30, 98]);
+    }
+
+    #[test]
+    fn registers_named_presets() {
+        Presets::register(
+            "test-dark-blues",
+            RandomColor::new()
+                .hue(Gamut::Blue)
+                .luminosity(Luminosity::Dark)
+                .seed(42)
+                .clone(),
+        );
+
+        let mut preset = RandomColor::from_preset("test-dark-blues").unwrap();
+
+        assert_eq!(preset.hue, Some(Gamut::Blue));
+        assert_eq!(
+            preset.to_hex(),
+            RandomColor::from_preset("test-dark-blues")
+                .unwrap()
+                .to_hex()
+        );
+        assert!(Presets::names().contains(&"test-dark-blues".to_string()));
+        assert!(Presets::unregister("test-dark-blues").is_some());
+        assert_eq!(RandomColor::from_preset("test-dark-blues"), None);
+    }
+
+    #[test]
+    fn audits_conversion_error() {
+        let test_case = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .audit_conversions();
+
+        assert_eq!(test_case.hsv, [191, 30, 98]);
+        assert_eq!(test_case.rgb, [174, 236, 249]);
+        assert_eq!(test_case.hsl, [191, 88, 83]);
+        assert!(test_case.rgb_error < 1.0);
+        assert!(test_case.rgb_delta_e < 0.5);
+        assert!(test_case.is_within(1.0));
+    }
+
+    #[test]
+    fn conversion_error_is_bounded() {
+        let mut random_color = RandomColor::new();
+        random_color.seed(7);
+
+        for _ in 0..1000 {
+            let audit = random_color.audit_conversions();
+
+            assert!(audit.hsl_error < 1.0, "{:?}", audit);
+            assert!(audit.hsv_round_trip_error <= 5, "{:?}", audit);
+            assert!(audit.rgb_error < 5.0, "{:?}", audit);
+        }
+    }
+
+    #[test]
+    fn samples_colors_at_the_edge() {
+        let dictionary = ColorDictionary::new();
+        let mut random_color = RandomColor::new();
+        random_color.seed(42).sampling(Sampling::Edge);
+
+        for _ in 0..100 {
+            let [h, s, v] = random_color.to_hsv_array().map(|c| c as i64);
+            let b_min = dictionary.get_minimum_value(&h, &s);
+
+            assert!((b_min..=b_min + EDGE_TOLERANCE).contains(&v));
+        }
+    }
+
+    #[test]
+    fn blends_between_luminosities() {
+        let mut from = RandomColor::new();
+        from.seed(42)
+            .luminosity_blend(Luminosity::Light, Luminosity::Bright, 0.0);
+        let mut light = RandomColor::new();
+        light.seed(42).luminosity(Luminosity::Light);
+
+        let mut to = RandomColor::new();
+        to.seed(42)
+            .luminosity_blend(Luminosity::Light, Luminosity::Bright, 1.0);
+        let mut bright = RandomColor::new();
+        bright.seed(42).luminosity(Luminosity::Bright);
+
+        for _ in 0..10 {
+            assert_eq!(from.to_hsv_array(), light.to_hsv_array());
+            assert_eq!(to.to_hsv_array(), bright.to_hsv_array());
+        }
+
+        let mut halfway = RandomColor::new();
+        halfway.seed(42).hue(Gamut::Blue).luminosity_blend(
+            Luminosity::Light,
+            Luminosity::Bright,
+            0.5,
+        );
+
+        for _ in 0..100 {
+            let [_, s, _] = halfway.to_hsv_array();
+
+            assert!((38..78).contains(&s));
+        }
+    }
+
+    #[test]
+    fn generates_styles() {
+        let styles = [
+            (Style::Pastel, 20..=39, 88..=100),
+            (Style::Neon, 90..=100, 95..=100),
+            (Style::Muted, 20..=39, 45..=69),
+            (Style::Vivid, 70..=89, 75..=94),
+        ];
+
+        for (style, saturation, brightness) in styles {
+            let mut random_color = RandomColor::new();
+            random_color
+                .hue(Gamut::Green)
+                .seed(42)
+                .luminosity(Luminosity::Dark)
+                .style(style);
+
+            let colors: Vec<[u32; 3]> = (0..300).map(|_| random_color.to_hsv_array()).collect();
+
+            for [_, s, v] in &colors {
+                assert!(saturation.contains(s), "{:?} {}", style, s);
+                assert!(brightness.contains(v), "{:?} {}", style, v);
+            }
+
+            if style == Style::Neon {
+                assert!(colors.iter().any(|[_, s, _]| *s == 100));
+                assert!(colors.iter().any(|[_, _, v]| *v == 100));
+            }
+        }
+    }
+
+    #[test]
+    fn generates_alpha_ramp() {
+        let ramp = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .alpha_ramp(4);
+
+        assert_eq!(
+            ramp.iter()
+                .map(|c| c.to_rgba_array())
+                .collect::<Vec<[u8; 4]>>(),
+            vec![
+                [174, 236, 249, 64],
+                [174, 236, 249, 128],
+                [174, 236, 249, 191],
+                [174, 236, 249, 255],
+            ]
+        );
+    }
+
+    #[test]
+    fn generates_variations_of_a_single_color() {
+        let variations = RandomColor::new()
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .seed(42)
+            .variations(20, 4);
+
+        assert_eq!(variations.len(), 20);
+
+        for color in variations.iter() {
+            let [h, s, v] = color.to_hsv_array();
+
+            assert!((186..=196).contains(&h));
+            assert!((28..=32).contains(&s));
+            assert!((93..=100).contains(&v));
+        }
+
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Blue).luminosity(Luminosity::Light);
+
+        assert_eq!(
+            rc.seed(42).variations(20, -4),
+            rc.seed(42).variations(20, 4)
+        );
+    }
+
+    #[test]
+    fn jitters_channels_around_a_base_color() {
+        let base = Color::new(250, 128, 3, 200);
+        let mut random_color = RandomColor::new();
+        random_color.seed(42);
+
+        assert_eq!(random_color.jitter(base), base);
+
+        random_color.channel_jitter(10, 0, 5);
+
+        for _ in 0..100 {
+            let color = random_color.jitter(base);
+
+            assert!((240..=255).contains(&color.r));
+            assert_eq!(color.g, 128);
+            assert!((0..=8).contains(&color.b));
+            assert_eq!(color.a, 200);
+        }
+    }
+
+    #[test]
+    fn mixes_two_generators() {
+        let mut brand = RandomColor::new();
+        brand.hue(Gamut::Blue).luminosity(Luminosity::Dark);
+        let mut accents = RandomColor::new();
+        accents.hue(Gamut::Yellow).luminosity(Luminosity::Bright);
+
+        let mut mix = RandomColor::mix_of(brand.clone(), accents.clone(), 0.8);
+        mix.seed(42);
+        let mut same_mix = RandomColor::mix_of(brand, accents, 0.8);
+        same_mix.seed(42);
+
+        let hues: Vec<u32> = (0..100).map(|_| mix.to_hsv_array()[0]).collect();
+        let blues = hues.iter().filter(|h| (179..=257).contains(*h)).count();
+        let yellows = hues.iter().filter(|h| (47..=62).contains(*h)).count();
+
+        assert_eq!(blues + yellows, 100);
+        assert!((65..=95).contains(&blues));
+        assert_eq!(
+            hues,
+            (0..100)
+                .map(|_| same_mix.to_hsv_array()[0])
+                .collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn keeps_rotating_sub_generators_of_a_mix() {
+        let gamuts = [Gamut::Blue, Gamut::Green, Gamut::Purple];
+        let mut rotating = RandomColor::new();
+        rotating.rotate_gamuts(&gamuts);
+
+        let mut mix = RandomColor::mix_of(rotating, RandomColor::new(), 1.0);
+        mix.seed(42);
+
+        let dictionary = ColorDictionary::new();
+
+        for i in 0..9 {
+            let [h, _, _] = mix.to_hsv_array();
+            let range = dictionary.get_color_from_gamut(&gamuts[i % 3]).range;
+
+            assert!((range[0]..=range[1]).contains(&(h as i64)));
+        }
+    }
+
+    #[test]
+    fn falls_back_to_the_first_satisfying_generator() {
+        let mut strict = RandomColor::new();
+        strict.hue(Gamut::Blue).seed(42);
+        let mut relaxed = RandomColor::new();
+        relaxed.hue(Gamut::Red).seed(42);
+
+        let color =
+            RandomColor::first_satisfying(&mut [strict.clone(), relaxed.clone()], |color| {
+                color.temperature() == Temperature::Warm
+            })
+            .unwrap();
+
+        assert_eq!(color.temperature(), Temperature::Warm);
+        assert_eq!(color, relaxed.to_color());
+        assert_eq!(
+            RandomColor::first_satisfying(&mut [strict], |color| color.r > 200 && color.b < 50),
+            None
+        );
+    }
+
+    #[test]
+    fn converts_edge_hues_exactly_with_algorithm_v2() {
+        let mut random_color = RandomColor::new();
+        random_color.on_candidate(|_| Candidate::Adjust(0, 100, 100));
+
+        assert_eq!(random_color.to_rgb_array(), [255, 4, 0]);
+
+        random_color.algorithm_version(AlgorithmVersion::V2);
+
+        assert_eq!(random_color.to_rgb_array(), [255, 0, 0]);
+
+        random_color.on_candidate(|_| Candidate::Adjust(120, 50, 50));
+
+        assert_eq!(random_color.to_rgb_array(), [64, 128, 64]);
+    }
+
+    #[test]
+    fn algorithm_v2_rounds_channels() {
+        let mut random_color = RandomColor::new();
+        random_color
+            .algorithm_version(AlgorithmVersion::V2)
+            .seed(42);
+
+        for _ in 0..1000 {
+            let audit = random_color.audit_conversions();
+
+            assert!(audit.rgb_error <= 0.5 + f32::EPSILON * 255.0, "{:?}", audit);
+        }
+    }
+
+    #[test]
+    fn algorithm_v2_keeps_f32_channels_unquantized() {
+        let mut random_color = RandomColor::new();
+        random_color
+            .hue(Gamut::Blue)
+            .luminosity(Luminosity::Light)
+            .algorithm_version(AlgorithmVersion::V2);
+
+        let exact = conversions::hsv_to_rgb([191.0, 0.3, 0.98]);
+
+        assert_eq!(random_color.seed(42).to_f32_rgb_array(), exact);
+        assert_eq!(random_color.seed(42).to_f32_rgba_array()[..3], exact);
+
+        random_color.algorithm_version(AlgorithmVersion::V1);
+
+        assert_eq!(
+            random_color.seed(42).to_f32_rgb_array(),
+            [174, 236, 249].map(|c| c as f32 / 255.0)
+        );
+    }
+
+    #[test]
+    fn forces_grayscale_colors() {
+        let mut random_color = RandomColor::new();
+        random_color.hue(Gamut::Red).seed(42).force_grayscale();
+
+        for _ in 0..100 {
+            let [r, g, b] = random_color.to_rgb_array();
+
+            assert!(r == g && g == b);
         }
+
+        assert_eq!(random_color.to_hsv_array()[1], 0);
     }
-}
 
-#[cfg(feature = "rgb_support")]
-impl From<RandomColor> for rgb::Rgba<u8> {
-    fn from(value: RandomColor) -> Self {
-        let rgba = value.into_rgba_array();
+    #[test]
+    fn generates_deterministic_test_vectors() {
+        let vectors = test_vectors::test_vectors(42, 45);
 
-        rgb::Rgba {
-            r: rgba[0],
-            g: rgba[1],
-            b: rgba[2],
-            a: rgba[3],
-        }
+        assert_eq!(vectors.len(), 45);
+        assert_eq!(vectors, test_vectors::test_vectors(42, 45));
+        assert!(vectors.iter().all(|vector| vector.hsv[0] < 360));
+        assert_eq!(
+            vectors[33].to_string(),
+            "seed=75 hue=Some(Blue) luminosity=Some(Light) hsv=216,30,98 rgb=174,204,249 hex=#aeccf9"
+        );
     }
-}
 
-#[cfg(feature = "rgb_support")]
-impl From<&mut RandomColor> for rgb::Rgba<u8> {
-    fn from(value: &mut RandomColor) -> Self {
-        let rgba = value.to_rgba_array();
+    #[test]
+    fn generates_hue_locked_luminosity_set() {
+        let set = RandomColor::new()
+            .hue(Gamut::Green)
+            .seed(42)
+            .generate_luminosity_set();
 
-        rgb::Rgba {
-            r: rgba[0],
-            g: rgba[1],
-            b: rgba[2],
-            a: rgba[3],
+        let hues = [set.light, set.default, set.bright, set.dark].map(|c| c.to_hsv_array()[0]);
+
+        assert!(hues.iter().all(|h| h.abs_diff(hues[0]) <= 1));
+        assert!(set.light.to_hsv_array()[1] < set.bright.to_hsv_array()[1]);
+        assert!(set.dark.to_hsv_array()[2] < set.light.to_hsv_array()[2]);
+    }
+
+    #[test]
+    fn generates_readable_ui_state_colors() {
+        let mut random_color = RandomColor::new();
+        random_color.seed(42);
+
+        for _ in 0..200 {
+            let colors = random_color.to_ui_state_colors();
+
+            assert!(colors.text_contrast() >= 4.5, "{:?}", colors);
+            assert!(colors.text.contrast_ratio(&colors.default) >= 4.5);
+            assert!(colors.hover_text.contrast_ratio(&colors.hover) >= 4.5);
+            assert!(colors.active_text.contrast_ratio(&colors.active) >= 4.5);
+            assert_eq!(colors.active, colors.border);
         }
     }
-}
 
-#[cfg(feature = "palette_support")]
-impl From<RandomColor> for Srgb {
-    fn from(value: RandomColor) -> Self {
-        let rgb = value.into_f32_rgb_array();
+    #[test]
+    fn weights_gamuts_from_counts() {
+        let mut random_color = RandomColor::new();
+        random_color
+            .seed(42)
+            .weights_from_counts(&[(Gamut::Blue, 9), (Gamut::Red, 1)]);
 
-        Srgb::new(rgb[0], rgb[1], rgb[2])
-    }
-}
+        let weights = random_color.gamut_weights.clone().unwrap();
 
-#[cfg(feature = "palette_support")]
-impl From<&mut RandomColor> for Srgb {
-    fn from(value: &mut RandomColor) -> Self {
-        let rgb = value.to_f32_rgb_array();
+        assert_eq!(weights.len(), 7);
+        assert!(weights.contains(&(Gamut::Blue, 100)));
+        assert!(weights.contains(&(Gamut::Red, 500)));
+        assert!(weights.contains(&(Gamut::Green, 1000)));
 
-        Srgb::new(rgb[0], rgb[1], rgb[2])
+        random_color.weights_from_counts(&[(Gamut::Monochrome, 3)]);
+
+        assert!(random_color
+            .gamut_weights
+            .unwrap()
+            .contains(&(Gamut::Monochrome, 250)));
     }
-}
 
-#[cfg(feature = "palette_support")]
-impl From<RandomColor> for Srgba {
-    fn from(value: RandomColor) -> Self {
-        let rgba = value.into_f32_rgba_array();
+    #[test]
+    fn rotates_through_gamuts() {
+        let mut random_color = RandomColor::new();
+        random_color
+            .seed(42)
+            .rotate_gamuts(&[Gamut::Blue, Gamut::Green, Gamut::Purple]);
 
-        Srgba::new(rgba[0], rgba[1], rgba[2], rgba[3])
+        let dictionary = ColorDictionary::new();
+        let gamuts = [Gamut::Blue, Gamut::Green, Gamut::Purple];
+
+        for i in 0..9 {
+            let [h, _, _] = random_color.to_hsv_array();
+            let range = dictionary.get_color_from_gamut(&gamuts[i % 3]).range;
+
+            assert!((range[0]..=range[1]).contains(&(h as i64)));
+        }
     }
-}
 
-#[cfg(feature = "palette_support")]
-impl From<&mut RandomColor> for Srgba {
-    fn from(value: &mut RandomColor) -> Self {
-        let rgba = value.to_f32_rgba_array();
+    #[test]
+    fn rotates_through_gamuts_past_rejected_candidates() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
 
-        Srgba::new(rgba[0], rgba[1], rgba[2], rgba[3])
+        let attempts = AtomicUsize::new(0);
+        let mut random_color = RandomColor::new();
+        random_color
+            .seed(42)
+            .rotate_gamuts(&[Gamut::Blue, Gamut::Green, Gamut::Purple])
+            .on_candidate(
+                move |_| match attempts.fetch_add(1, Ordering::Relaxed) % 2 {
+                    0 => Candidate::Reject,
+                    _ => Candidate::Accept,
+                },
+            );
+
+        let dictionary = ColorDictionary::new();
+        let gamuts = [Gamut::Blue, Gamut::Green, Gamut::Purple];
+
+        for i in 0..9 {
+            let [h, _, _] = random_color.to_hsv_array();
+            let range = dictionary.get_color_from_gamut(&gamuts[i % 3]).range;
+
+            assert!((range[0]..=range[1]).contains(&(h as i64)));
+        }
     }
-}
 
-#[cfg(feature = "ecolor_support")]
-impl From<RandomColor> for Color32 {
-    fn from(value: RandomColor) -> Self {
-        let rgba = value.into_rgba_array();
-        Color32::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3])
+    #[test]
+    fn maps_values_between_anchors() {
+        let black = Color::new(0, 0, 0, 255);
+        let white = Color::new(255, 255, 255, 255);
+        let mapper = ValueMapper::new(&[(100.0, white), (0.0, black)]);
+
+        assert_eq!(mapper.map(-5.0), Some(black));
+        assert_eq!(mapper.map(100.0), Some(white));
+        assert_eq!(mapper.map(50.0), Some(Color::new(99, 99, 99, 255)));
+        assert_eq!(ValueMapper::new(&[]).map(0.0), None);
+
+        let mapper = RandomColor::new().seed(42).to_value_mapper(0.0, 1.0, 3);
+        let low = mapper.map(0.0).unwrap();
+        let high = mapper.map(1.0).unwrap();
+
+        assert!(low.to_lab()[0] <= high.to_lab()[0]);
     }
-}
 
-#[cfg(feature = "ecolor_support")]
-impl From<&mut RandomColor> for Color32 {
-    fn from(value: &mut RandomColor) -> Self {
-        let rgba = value.to_rgba_array();
-        Color32::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3])
+    #[test]
+    fn regenerates_palette_keeping_anchors() {
+        let mut palette = RandomColor::new().seed(42).to_palette(5);
+        let original = palette.clone();
+
+        palette.regenerate_keeping(RandomColor::new().seed(7), &[1, 4, 9]);
+
+        assert_eq!(palette.len(), 5);
+        assert_eq!(palette.colors[1], original.colors[1]);
+        assert_eq!(palette.colors[4], original.colors[4]);
+        assert_ne!(palette.colors[0], original.colors[0]);
+        assert_ne!(palette.colors[2], original.colors[2]);
     }
-}
 
-#[cfg(feature = "ecolor_support")]
-impl From<RandomColor> for Rgba {
-    fn from(value: RandomColor) -> Self {
-        let rgba = value.into_f32_rgba_array();
-        Rgba::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3])
+    #[test]
+    fn renders_palette_html_preview() {
+        let palette = Palette::new(vec![
+            Color::new(174, 236, 249, 255),
+            Color::new(255, 0, 0, 255),
+        ]);
+
+        let html = palette.to_html_preview();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<code>#aeecf9</code><code>hsl(190, 86%, 83%)</code>"));
+        assert!(html.contains("<code>#ff0000</code><code>hsl(0, 100%, 50%)</code>"));
+        assert_eq!(html.matches("class=\"swatch\"").count(), 2);
     }
-}
 
-#[cfg(feature = "ecolor_support")]
-impl From<&mut RandomColor> for Rgba {
-    fn from(value: &mut RandomColor) -> Self {
-        let rgba = value.to_f32_rgba_array();
-        Rgba::from_rgba_unmultiplied(rgba[0], rgba[1], rgba[2], rgba[3])
+    #[test]
+    fn generates_symmetric_identicon() {
+        let identicon = RandomColor::new().seed("identicon").to_identicon();
+
+        for row in identicon.cells.iter() {
+            assert_eq!(row[0], row[4]);
+            assert_eq!(row[1], row[3]);
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn generates_same_identicon_for_same_seed() {
+        let a = RandomColor::new().seed(42).to_identicon();
+        let b = RandomColor::new().seed(42).to_identicon();
+
+        assert_eq!(a, b);
+    }
 
     #[test]
-    fn generates_different_colors_using_the_same_instance() {
+    fn draws_identicon_background_from_custom_dictionary() {
+        let mut dictionary = ColorDictionary::new();
+        dictionary.monochrome = ColorInformation::new([0, 0], vec![[0, 90], [100, 90]]);
+
         let mut rc = RandomColor::new();
+        rc.dictionary(dictionary).exact_hue(200).seed(42);
 
-        assert_ne!(rc.to_rgb_string(), rc.to_rgb_string());
+        for _ in 0..20 {
+            let background = rc.to_identicon().background;
+
+            assert!(background.iter().max() >= Some(&240), "{:?}", background);
+        }
     }
 
     #[test]
-    fn generates_color_as_hsv_array() {
-        let test_case = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_hsv_array();
+    fn renders_identicon_as_rgba_buffer() {
+        let identicon = Identicon::new([[true; 5]; 5], [1, 2, 3], [255, 255, 255]);
+        let buffer = identicon.to_rgba_buffer(2);
 
-        assert_eq!(test_case, [191, 30, 98]);
+        assert_eq!(buffer.len(), 10 * 10 * 4);
+        assert_eq!(&buffer[0..4], &[1, 2, 3, 255]);
     }
 
     #[test]
-    fn generates_color_as_rgb_string() {
-        let test_case = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_rgb_string();
-
-        assert_eq!(test_case, "rgb(174, 236, 249)");
+    fn renders_identicon_as_svg() {
+        let mut pattern = [[false; 5]; 5];
+        pattern[0][0] = true;
+        let svg = Identicon::new(pattern, [1, 2, 3], [255, 255, 255]).to_svg(64);
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"1\" height=\"1\" fill=\"#010203\"/>"));
+        assert!(svg.contains("<rect x=\"4\" y=\"0\" width=\"1\" height=\"1\" fill=\"#010203\"/>"));
+        assert!(svg.ends_with("</svg>"));
     }
 
     #[test]
-    fn generates_color_as_rgba_string() {
-        let test_case = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_rgba_string();
+    fn exports_showcase_palette_as_css_variables() {
+        let css = showcase::palette_css_variables(42, 2, "brand");
+        let palette = RandomColor::new().seed(42).to_palette(2);
 
-        assert_eq!(test_case, "rgba(174, 236, 249, 1)");
+        assert_eq!(
+            css,
+            format!(
+                ":root {{\n  --brand-1: {};\n  --brand-2: {};\n}}\n",
+                palette.colors[0].to_hex(),
+                palette.colors[1].to_hex()
+            )
+        );
     }
 
     #[test]
-    fn generates_color_as_rgb_array() {
-        let test_case = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_rgb_array();
+    fn picks_showcase_avatar_colors() {
+        let (background, text) = showcase::avatar_colors("ada");
 
-        assert_eq!(test_case, [174, 236, 249]);
+        assert_eq!(showcase::avatar_colors("ada"), (background, text));
+        assert!(text == Color::new(0, 0, 0, 255) || text == Color::new(255, 255, 255, 255));
+        assert!(background.contrast_ratio(&text) >= 21.0_f32.sqrt());
     }
 
+    /* Optional Feature Tests */
+
     #[test]
-    fn generates_color_as_f32_rgb_array() {
+    #[cfg(feature = "ansi_preview")]
+    fn renders_ansi_preview() {
         let test_case = RandomColor::new()
             .hue(Gamut::Blue)
             .luminosity(Luminosity::Light)
             .seed(42)
             .alpha(1.0)
-            .to_f32_rgb_array();
+            .preview_string();
 
-        assert_eq!(test_case, [0.68235296, 0.9254902, 0.9764706]);
+        assert_eq!(test_case, "\x1b[48;2;174;236;249m      \x1b[0m #aeecf9");
     }
 
     #[test]
-    fn generates_color_as_f32_rgba_array() {
-        let test_case = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_f32_rgba_array();
+    #[cfg(feature = "ansi_preview")]
+    fn renders_palette_ansi_preview() {
+        let palette = RandomColor::new().seed(42).to_palette(3);
+
+        assert_eq!(palette.preview_string().lines().count(), 3);
+        assert!(palette
+            .preview_string()
+            .ends_with(&format!("{}\n", palette.colors[2].to_hex())));
+    }
 
-        assert_eq!(test_case, [0.68235296, 0.9254902, 0.9764706, 1.0]);
+    #[test]
+    #[cfg(feature = "ansi_preview")]
+    fn renders_palette_block_rows() {
+        let palette = Palette::new(vec![
+            Color::new(255, 0, 0, 255),
+            Color::new(0, 255, 0, 255),
+            Color::new(0, 0, 255, 255),
+        ]);
+
+        assert_eq!(
+            palette.to_block_rows(8),
+            "\x1b[38;2;255;0;0m\u{2588}\u{2588}\u{2588}\x1b[38;2;0;255;0m\u{2588}\u{2588}\u{2588}\x1b[38;2;0;0;255m\u{2588}\u{2588}\x1b[0m\n"
+        );
+        assert_eq!(palette.to_block_rows(2).lines().count(), 2);
+        assert_eq!(
+            palette.to_block_rows(2).lines().last(),
+            Some("\x1b[38;2;0;0;255m\u{2588}\x1b[0m")
+        );
+        assert!(palette.to_block_rows(0).is_empty());
     }
 
     #[test]
-    fn generates_color_as_hsl_string() {
-        let test_case = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_hsl_string();
+    #[cfg(feature = "ansi_preview")]
+    fn renders_showcase_terminal_palette() {
+        let preview = showcase::terminal_palette(42, 3, 12);
 
-        assert_eq!(test_case, "hsl(191, 88%, 16%)");
+        assert_eq!(preview.lines().count(), 4);
     }
 
     #[test]
-    fn generates_color_as_hsla_string() {
-        let test_case = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_hsla_string();
+    #[cfg(feature = "soft_proofing")]
+    fn soft_proofs_colors_on_target_displays() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Blue).luminosity(Luminosity::Light).alpha(1.0);
 
-        assert_eq!(test_case, "hsl(191, 88%, 16%, 1)");
+        let color = rc.seed(42).to_color();
+        let srgb = DisplayProfile::srgb();
+
+        assert!(srgb.proof(color).approx_eq(&color, 0.5));
+        assert!(srgb.in_gamut(color));
+
+        let narrow = DisplayProfile::new([[0.5, 0.33], [0.3, 0.5], [0.2, 0.15]], [0.3127, 0.329]);
+        let red = Color::new(255, 0, 0, 255);
+
+        assert!(!narrow.in_gamut(red));
+        assert!(narrow.proof(red).delta_e(&red) > 10.0);
+
+        let warm = DisplayProfile::new(srgb.primaries, [0.3457, 0.3585]);
+        let white = warm.proof(Color::new(255, 255, 255, 255));
+
+        assert!(white.r > white.b);
+        assert_eq!(rc.seed(42).to_soft_proof(&srgb), srgb.proof(color));
+        assert_eq!(warm.proof_mapped(red, GamutMapping::Clip), warm.proof(red));
     }
 
     #[test]
-    fn generates_color_as_hsl_array() {
-        let test_case = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_hsl_array();
+    #[cfg(feature = "textures")]
+    fn fills_checkerboard_texture() {
+        let mut buffer = vec![0u8; 4 * 4 * 4];
+        let mut rc = RandomColor::new();
+        rc.seed(42);
+
+        texture::checkerboard(&mut rc.clone(), &mut buffer, 4, 2);
+        let first = rc.to_rgba_array();
+        let second = rc.to_rgba_array();
 
-        assert_eq!(test_case, [191, 88, 16]);
+        assert_eq!(&buffer[0..4], &first);
+        assert_eq!(&buffer[8..12], &second);
+        assert_eq!(&buffer[40..44], &first);
     }
 
     #[test]
-    fn generates_color_as_hex() {
-        let test_case = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_hex();
+    #[cfg(feature = "textures")]
+    fn fills_vertical_stripes_texture() {
+        let mut buffer = vec![0u8; 4 * 2 * 4];
+        let mut rc = RandomColor::new();
+        rc.seed(42);
+
+        texture::stripes(
+            &mut rc,
+            &mut buffer,
+            4,
+            1,
+            texture::StripeDirection::Vertical,
+        );
 
-        assert_eq!(test_case, "#aeecf9");
+        assert_eq!(&buffer[0..4], &buffer[16..20]);
+        assert_eq!(&buffer[12..16], &buffer[28..32]);
     }
 
     #[test]
-    fn to_hex_is_rrggbb() {
-        let test_case = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_hex();
-        let [r, g, b] = RandomColor::new()
-            .hue(Gamut::Blue)
-            .luminosity(Luminosity::Light)
-            .seed(42)
-            .alpha(1.0)
-            .to_rgb_array();
+    #[cfg(feature = "textures")]
+    fn fills_textures_with_a_partial_last_row() {
+        let mut buffer = vec![0u8; 12];
+        let mut rc = RandomColor::new();
+        rc.seed(42).alpha(1.0);
+
+        texture::stripes(
+            &mut rc,
+            &mut buffer,
+            2,
+            1,
+            texture::StripeDirection::Horizontal,
+        );
 
-        assert_eq!(test_case, format!("#{:02x}{:02x}{:02x}", r, g, b).as_str());
+        assert_ne!(&buffer[8..12], &[0, 0, 0, 0]);
+
+        texture::voronoi(&mut rc, &mut buffer, 2, 3);
+
+        assert!(buffer.chunks_exact(4).all(|pixel| pixel[3] == 255));
     }
 
     #[test]
-    fn single_digit_hex_are_padded_by_to_two_chars() {
-        let test_case = RandomColor::new()
-            .luminosity(Luminosity::Dark)
-            .seed(5)
-            .to_hex();
+    #[cfg(feature = "textures")]
+    fn fills_voronoi_texture() {
+        let mut buffer = vec![0u8; 8 * 8 * 4];
+        let mut rc = RandomColor::new();
+        rc.seed(42).alpha(1.0);
 
-        assert_eq!(test_case, "#3e0496");
+        texture::voronoi(&mut rc, &mut buffer, 8, 3);
+
+        assert!(buffer.chunks_exact(4).all(|pixel| pixel[3] == 255));
     }
 
-    /* Optional Feature Tests */
+    #[test]
+    #[cfg(feature = "const_generation")]
+    fn generates_same_color_at_compile_time() {
+        const DEFAULT: [u8; 3] = random_color!("compile time");
+        const BLUE: [u8; 3] = random_color!("compile time", hue = Blue);
+        const DARK_RED: [u8; 3] = random_color!("compile time", hue = Red, luminosity = Dark);
+
+        assert_eq!(
+            DEFAULT,
+            RandomColor::new()
+                .seed(const_gen::seed_value("compile time"))
+                .to_rgb_array()
+        );
+        assert_eq!(
+            BLUE,
+            RandomColor::new()
+                .seed(const_gen::seed_value("compile time"))
+                .hue(Gamut::Blue)
+                .to_rgb_array()
+        );
+        assert_eq!(
+            DARK_RED,
+            RandomColor::new()
+                .seed(const_gen::seed_value("compile time"))
+                .hue(Gamut::Red)
+                .luminosity(Luminosity::Dark)
+                .to_rgb_array()
+        );
+
+        for seed in ["a", "b", "c", "d", "e", "f", "g", "h"] {
+            assert_eq!(
+                const_gen::generate_rgb(seed, None, Some(Luminosity::Light)),
+                RandomColor::new()
+                    .seed(const_gen::seed_value(seed))
+                    .luminosity(Luminosity::Light)
+                    .to_rgb_array()
+            );
+        }
+    }
 
     #[test]
     #[cfg(feature = "rgb_support")]
@@ -841,4 +5388,18 @@ mod tests {
             [0.68235296, 0.9254902, 0.9764706, 1.0]
         );
     }
+
+    #[test]
+    #[cfg(feature = "ecolor_support")]
+    fn ecolor_rgba_matches_premultiplied_output() {
+        let mut rc = RandomColor::new();
+        rc.hue(Gamut::Blue).luminosity(Luminosity::Light).alpha(0.5);
+
+        let converted = Rgba::from(rc.seed(42).clone());
+
+        assert_eq!(
+            converted.to_array(),
+            rc.seed(42).to_premultiplied_f32_rgba_array()
+        );
+    }
 }
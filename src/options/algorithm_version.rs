@@ -0,0 +1,13 @@
+/// The version of the algorithms used to convert generated colors, so that
+/// correctness fixes do not change the colors existing seeds produce.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlgorithmVersion {
+    /// The original conversion, which nudges the hue 0 to 1 and 360 to 359,
+    /// and floors the RGB channels.
+    #[default]
+    V1,
+    /// The corrected conversion, which keeps the hue as is, rounds the 8-bit
+    /// RGB channels to the nearest value and does not quantize the `f32`
+    /// ones.
+    V2,
+}
@@ -0,0 +1,25 @@
+use std::error::Error;
+use std::fmt;
+
+/// An error returned when setting an invalid alpha through
+/// `RandomColor::try_alpha`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlphaError {
+    /// The alpha is outside the range [0.0, 1.0].
+    OutOfRange(f32),
+    /// The alpha is NaN.
+    NotANumber,
+}
+
+impl fmt::Display for AlphaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AlphaError::OutOfRange(alpha) => {
+                write!(f, "alpha {} is outside the range [0.0, 1.0]", alpha)
+            }
+            AlphaError::NotANumber => write!(f, "alpha is NaN"),
+        }
+    }
+}
+
+impl Error for AlphaError {}
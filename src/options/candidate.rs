@@ -0,0 +1,45 @@
+use std::fmt;
+use std::sync::Arc;
+
+/// The decision of a candidate filter about a generated HSV candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Candidate {
+    /// Keep the candidate.
+    Accept,
+    /// Discard the candidate and generate a new one.
+    Reject,
+    /// Replace the candidate with the given hue, saturation and brightness.
+    Adjust(i64, i64, i64),
+}
+
+/// A user-provided rule applied to every HSV candidate inside the generation
+/// loop, set through `RandomColor::on_candidate`.
+#[derive(Clone)]
+pub struct CandidateFilter(Arc<dyn Fn([i64; 3]) -> Candidate + Send + Sync>);
+
+impl CandidateFilter {
+    /// Creates a new `CandidateFilter` instance.
+    pub fn new<F: Fn([i64; 3]) -> Candidate + Send + Sync + 'static>(filter: F) -> Self {
+        CandidateFilter(Arc::new(filter))
+    }
+
+    /// Applies the filter to an HSV candidate.
+    ///
+    /// Parameters:
+    /// * `hsv`: The hue, saturation and brightness of the candidate.
+    pub fn apply(&self, hsv: [i64; 3]) -> Candidate {
+        (self.0)(hsv)
+    }
+}
+
+impl fmt::Debug for CandidateFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CandidateFilter(..)")
+    }
+}
+
+impl PartialEq for CandidateFilter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
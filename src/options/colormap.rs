@@ -0,0 +1,48 @@
+/// A well-known sequential colormap shape.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colormap {
+    #[default]
+    Viridis,
+    Magma,
+    Inferno,
+    Plasma,
+}
+
+impl Colormap {
+    /// Returns the RGB anchor colors of the colormap, from its darkest end to
+    /// its lightest one.
+    pub fn anchors(&self) -> &'static [[u8; 3]] {
+        match self {
+            Colormap::Viridis => &[
+                [68, 1, 84],
+                [59, 82, 139],
+                [33, 145, 140],
+                [94, 201, 98],
+                [253, 231, 37],
+            ],
+            Colormap::Magma => &[
+                [0, 0, 4],
+                [59, 15, 112],
+                [140, 41, 129],
+                [222, 73, 104],
+                [254, 159, 109],
+                [252, 253, 191],
+            ],
+            Colormap::Inferno => &[
+                [0, 0, 4],
+                [66, 10, 104],
+                [147, 38, 103],
+                [221, 81, 58],
+                [252, 165, 10],
+                [252, 255, 164],
+            ],
+            Colormap::Plasma => &[
+                [13, 8, 135],
+                [126, 3, 168],
+                [204, 71, 120],
+                [248, 149, 64],
+                [240, 249, 33],
+            ],
+        }
+    }
+}
@@ -0,0 +1,29 @@
+/// A CSS color syntax, as used by `RandomColor::to_css`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CssFormat {
+    /// `#rrggbb`.
+    #[default]
+    Hex,
+    /// `#rrggbbaa`.
+    HexWithAlpha,
+    /// `rgb(r, g, b)`.
+    Rgb,
+    /// `rgba(r, g, b, a)`.
+    Rgba,
+    /// `rgb(r g b / a)`.
+    ModernRgb,
+    /// `hsl(h, s%, l%)`.
+    Hsl,
+    /// `hsl(h, s%, l%, a)`.
+    Hsla,
+    /// `hsl(h s% l% / a)`.
+    ModernHsl,
+    /// `hwb(h w% b%)`.
+    Hwb,
+    /// `lch(l% c h)`.
+    Lch,
+    /// `oklch(l% c h)`.
+    Oklch,
+    /// `color(display-p3 r g b / a)`.
+    DisplayP3,
+}
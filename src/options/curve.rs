@@ -0,0 +1,35 @@
+/// An easing curve reshaping uniform sampling within a range, e.g. to bias
+/// brightness towards the brighter end without changing its bounds.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub enum Curve {
+    /// Uniform sampling.
+    #[default]
+    Linear,
+    /// Quadratic ease-in, biasing towards the low end of the range.
+    EaseIn,
+    /// Quadratic ease-out, biasing towards the high end of the range.
+    EaseOut,
+    /// Smoothstep, biasing towards both ends of the range.
+    EaseInOut,
+    /// `t` raised to the given exponent, biasing towards the low end above 1
+    /// and towards the high end below 1.
+    Power(f32),
+}
+
+impl Curve {
+    /// Maps a uniform sample in the range [0.0, 1.0] through the curve.
+    ///
+    /// Parameters:
+    /// * `t`: The uniform sample.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Curve::Linear => t,
+            Curve::EaseIn => t * t,
+            Curve::EaseOut => 1.0 - (1.0 - t) * (1.0 - t),
+            Curve::EaseInOut => t * t * (3.0 - 2.0 * t),
+            Curve::Power(exponent) => t.powf(exponent.max(0.0)),
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use crate::conversions;
+
+/// The largest OKLab difference between a clipped color and the color it was
+/// clipped from that the CSS Color 4 algorithm accepts.
+const JUST_NOTICEABLE_DIFFERENCE: f32 = 0.02;
+
+/// The chroma resolution of the binary searches.
+const CHROMA_EPSILON: f32 = 1e-4;
+
+/// The tolerance on the sRGB channels below which a color is still
+/// considered inside the gamut.
+const GAMUT_TOLERANCE: f32 = 1e-4;
+
+/// How colors outside the sRGB gamut, e.g. interpolated in Lab, are brought
+/// into it before being output as 8-bit channels.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GamutMapping {
+    /// Clips each channel to the gamut, which is fast but can shift the hue
+    /// and lightness of saturated colors.
+    #[default]
+    Clip,
+    /// Reduces the OKLCH chroma until the color fits, keeping its lightness
+    /// and hue.
+    ReduceChroma,
+    /// The CSS Color Module Level 4 algorithm: reduces the OKLCH chroma, but
+    /// stops as soon as clipping the color is no longer noticeable, keeping
+    /// more of its colorfulness.
+    Css4,
+}
+
+impl GamutMapping {
+    /// Brings an sRGB color into the gamut and quantizes it.
+    ///
+    /// Parameters:
+    /// * `rgb`: The gamma-encoded sRGB color, with channels possibly outside
+    ///   of the range [0.0, 1.0].
+    pub(crate) fn map(&self, rgb: [f32; 3]) -> [u8; 3] {
+        let mapped = match self {
+            GamutMapping::Clip => rgb,
+            GamutMapping::ReduceChroma => reduce_chroma(rgb, None),
+            GamutMapping::Css4 => reduce_chroma(rgb, Some(JUST_NOTICEABLE_DIFFERENCE)),
+        };
+
+        mapped.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+}
+
+/// Binary searches the OKLCH chroma of a color for the most colorful
+/// version that fits the gamut, or whose clipped version is within
+/// `tolerance` of it in OKLab.
+fn reduce_chroma(rgb: [f32; 3], tolerance: Option<f32>) -> [f32; 3] {
+    if in_gamut(rgb) {
+        return rgb;
+    }
+
+    let [lightness, a, b] = conversions::rgb_to_oklab(rgb);
+
+    if lightness >= 1.0 {
+        return [1.0; 3];
+    }
+
+    if lightness <= 0.0 {
+        return [0.0; 3];
+    }
+
+    let [_, chroma, hue] = conversions::lab_to_lch([lightness, a, b]);
+    let with_chroma = |chroma: f32| {
+        let (sin, cos) = hue.to_radians().sin_cos();
+
+        conversions::oklab_to_rgb([lightness, chroma * cos, chroma * sin])
+    };
+    let clip = |rgb: [f32; 3]| rgb.map(|c| c.clamp(0.0, 1.0));
+    let difference = |rgb: [f32; 3]| {
+        let (from, to) = (
+            conversions::rgb_to_oklab(rgb),
+            conversions::rgb_to_oklab(clip(rgb)),
+        );
+
+        [0, 1, 2]
+            .map(|c| (from[c] - to[c]).powi(2))
+            .iter()
+            .sum::<f32>()
+            .sqrt()
+    };
+
+    if let Some(tolerance) = tolerance {
+        if difference(rgb) < tolerance {
+            return clip(rgb);
+        }
+    }
+
+    let (mut min, mut max) = (0.0, chroma);
+    let mut min_in_gamut = true;
+    let mut current = rgb;
+
+    while max - min > CHROMA_EPSILON {
+        let candidate = (min + max) / 2.0;
+        current = with_chroma(candidate);
+
+        if min_in_gamut && in_gamut(current) {
+            min = candidate;
+            continue;
+        }
+
+        match tolerance {
+            Some(tolerance) if difference(current) < tolerance => {
+                if tolerance - difference(current) < CHROMA_EPSILON {
+                    break;
+                }
+
+                min_in_gamut = false;
+                min = candidate;
+            }
+            _ => max = candidate,
+        }
+    }
+
+    clip(current)
+}
+
+fn in_gamut(rgb: [f32; 3]) -> bool {
+    rgb.iter()
+        .all(|c| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(c))
+}
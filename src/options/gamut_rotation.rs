@@ -0,0 +1,35 @@
+use super::Gamut;
+
+/// A list of gamuts that successive colors rotate through in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GamutRotation {
+    /// The gamuts to rotate through.
+    pub gamuts: Vec<Gamut>,
+    /// The index of the gamut of the next color.
+    pub next: usize,
+}
+
+impl GamutRotation {
+    /// Creates a new `GamutRotation` instance starting at the first gamut.
+    ///
+    /// Parameters:
+    /// * `gamuts`: The gamuts to rotate through.
+    pub fn new(gamuts: &[Gamut]) -> GamutRotation {
+        GamutRotation {
+            gamuts: gamuts.to_vec(),
+            next: 0,
+        }
+    }
+
+    /// Returns the gamut of the next color, or `None` if there are no gamuts.
+    pub(crate) fn peek(&self) -> Option<Gamut> {
+        self.gamuts
+            .get(self.next % self.gamuts.len().max(1))
+            .copied()
+    }
+
+    /// Moves on to the following gamut once a color has been generated.
+    pub(crate) fn advance(&mut self) {
+        self.next = (self.next + 1) % self.gamuts.len().max(1);
+    }
+}
@@ -0,0 +1,26 @@
+use super::Luminosity;
+
+/// A blend between the saturation and brightness windows of two luminosity
+/// presets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LuminosityBlend {
+    /// The luminosity used when `t` is 0.0.
+    pub from: Luminosity,
+    /// The luminosity used when `t` is 1.0.
+    pub to: Luminosity,
+    /// The position of the blend, in the range [0.0, 1.0].
+    pub t: f32,
+}
+
+impl LuminosityBlend {
+    /// Interpolates between the window of `from` and the window of `to`.
+    ///
+    /// Parameters:
+    /// * `from`: The window of the `from` luminosity.
+    /// * `to`: The window of the `to` luminosity.
+    pub(crate) fn interpolate(&self, from: (i64, i64), to: (i64, i64)) -> (i64, i64) {
+        let lerp = |a: i64, b: i64| (a as f32 + (b - a) as f32 * self.t).round() as i64;
+
+        (lerp(from.0, to.0), lerp(from.1, to.1))
+    }
+}
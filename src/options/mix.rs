@@ -0,0 +1,13 @@
+use crate::RandomColor;
+
+/// A weighted mix of two generator configurations, set through
+/// `RandomColor::mix_of`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mix {
+    /// The generator sampled with probability `weight`.
+    pub a: RandomColor,
+    /// The generator sampled otherwise.
+    pub b: RandomColor,
+    /// The probability of sampling `a`, in the range [0.0, 1.0].
+    pub weight: f32,
+}
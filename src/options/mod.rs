@@ -1,7 +1,46 @@
+mod algorithm_version;
+mod alpha_error;
+mod candidate;
+mod colormap;
+mod css_format;
+mod curve;
 mod gamut;
+mod gamut_mapping;
+mod gamut_rotation;
 mod luminosity;
+mod luminosity_blend;
+mod mix;
+mod near;
+mod retry_policy;
+mod sampling;
+mod season;
 mod seed;
+mod style;
+mod value_range;
+mod vertex_layout;
+mod white_extraction;
+mod ycbcr_standard;
 
+pub use self::algorithm_version::AlgorithmVersion;
+pub use self::alpha_error::AlphaError;
+pub use self::candidate::{Candidate, CandidateFilter};
+pub use self::colormap::Colormap;
+pub use self::css_format::CssFormat;
+pub use self::curve::Curve;
 pub use self::gamut::Gamut;
+pub use self::gamut_mapping::GamutMapping;
+pub use self::gamut_rotation::GamutRotation;
 pub use self::luminosity::Luminosity;
-pub use self::seed::Seed;
\ No newline at end of file
+pub use self::luminosity_blend::LuminosityBlend;
+pub use self::mix::Mix;
+pub use self::near::Near;
+pub use self::retry_policy::{Fallback, RetryPolicy};
+pub use self::sampling::Sampling;
+pub use self::season::{Season, SeasonalWeights};
+pub(crate) use self::seed::hash_str;
+pub use self::seed::Seed;
+pub use self::style::Style;
+pub use self::value_range::ValueRange;
+pub use self::vertex_layout::VertexLayout;
+pub use self::white_extraction::WhiteExtraction;
+pub use self::ycbcr_standard::YCbCrStandard;
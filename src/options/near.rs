@@ -0,0 +1,13 @@
+/// A neighborhood around a reference color in the HSV space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Near {
+    /// The hue of the reference color, in the range [0, 360).
+    pub hue: i64,
+    /// The saturation of the reference color, in the range [0, 100].
+    pub saturation: i64,
+    /// The brightness of the reference color, in the range [0, 100].
+    pub brightness: i64,
+    /// The maximum deviation from the reference color for each component,
+    /// in degrees for the hue and percentage points otherwise.
+    pub tolerance: i64,
+}
@@ -0,0 +1,35 @@
+use crate::color::Color;
+
+/// What constrained generation returns when no candidate satisfies the
+/// constraints within the allowed number of attempts.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fallback {
+    /// The candidate that came closest to satisfying the constraints.
+    #[default]
+    BestCandidate,
+    /// The last candidate that was tried.
+    LastCandidate,
+    /// A fixed color.
+    Color(Color),
+}
+
+/// Controls the rejection loop used by constrained generation, such as
+/// distinct palettes or minimum lightness gaps, trading latency for
+/// strictness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of candidates to try. At least one candidate is
+    /// always tried.
+    pub max_attempts: usize,
+    /// What to return when every attempt fails.
+    pub on_exhausted: Fallback,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 64,
+            on_exhausted: Fallback::BestCandidate,
+        }
+    }
+}
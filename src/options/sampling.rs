@@ -0,0 +1,11 @@
+/// Where in the color dictionary's acceptable region colors are sampled.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sampling {
+    /// Colors are sampled from anywhere inside the acceptable region.
+    #[default]
+    Interior,
+    /// Colors are sampled close to the lower bounds of the acceptable region,
+    /// that is, the most saturated colors representable for each hue and
+    /// brightness, for maximal punchiness.
+    Edge,
+}
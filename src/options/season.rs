@@ -0,0 +1,88 @@
+use crate::options::Gamut;
+
+/// A season of the year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    /// Returns the meteorological season of the given month in the northern
+    /// hemisphere.
+    ///
+    /// Parameters:
+    /// * `month`: The month, in the range [1, 12].
+    pub fn from_month(month: u32) -> Season {
+        match month {
+            3..=5 => Season::Spring,
+            6..=8 => Season::Summer,
+            9..=11 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+}
+
+/// The gamut weights used for each season by `RandomColor::for_date`.
+///
+/// Seasons follow the northern hemisphere; swap the weights of opposite
+/// seasons for the southern hemisphere.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeasonalWeights {
+    /// The gamut weights used in spring.
+    pub spring: Vec<(Gamut, u32)>,
+    /// The gamut weights used in summer.
+    pub summer: Vec<(Gamut, u32)>,
+    /// The gamut weights used in autumn.
+    pub autumn: Vec<(Gamut, u32)>,
+    /// The gamut weights used in winter.
+    pub winter: Vec<(Gamut, u32)>,
+}
+
+impl SeasonalWeights {
+    /// Returns the gamut weights of the given season.
+    ///
+    /// Parameters:
+    /// * `season`: The season to get the weights for.
+    pub fn get(&self, season: Season) -> &[(Gamut, u32)] {
+        match season {
+            Season::Spring => &self.spring,
+            Season::Summer => &self.summer,
+            Season::Autumn => &self.autumn,
+            Season::Winter => &self.winter,
+        }
+    }
+}
+
+impl Default for SeasonalWeights {
+    fn default() -> Self {
+        SeasonalWeights {
+            spring: vec![
+                (Gamut::Green, 3),
+                (Gamut::Pink, 3),
+                (Gamut::Yellow, 2),
+                (Gamut::Purple, 1),
+            ],
+            summer: vec![
+                (Gamut::Yellow, 3),
+                (Gamut::Blue, 3),
+                (Gamut::Orange, 2),
+                (Gamut::Green, 1),
+            ],
+            autumn: vec![
+                (Gamut::Orange, 3),
+                (Gamut::Red, 3),
+                (Gamut::Yellow, 2),
+                (Gamut::Purple, 1),
+            ],
+            winter: vec![
+                (Gamut::Blue, 3),
+                (Gamut::Purple, 2),
+                (Gamut::Monochrome, 2),
+                (Gamut::Red, 1),
+            ],
+        }
+    }
+}
@@ -1,7 +1,15 @@
+#[cfg(not(feature = "fast_hash"))]
 use std::collections::hash_map::DefaultHasher;
+#[cfg(not(feature = "fast_hash"))]
 use std::hash::{Hash, Hasher};
 
 /// A trait for types that can be used as seeds for the RandomColor struct.
+///
+/// Strings are hashed with the standard library's `DefaultHasher`, whose
+/// output may change between Rust releases. Enabling the `fast_hash` feature
+/// hashes them with 64-bit FNV-1a instead, which is faster for short keys and
+/// guaranteed to stay the same across Rust releases and platforms, at the
+/// cost of producing different colors than the default hasher.
 pub trait Seed {
     fn to_value(self) -> u64;
 }
@@ -32,24 +40,40 @@ impl Seed for u32 {
 
 impl Seed for String {
     fn to_value(self) -> u64 {
-        let mut hasher = DefaultHasher::new();
-        self.hash(&mut hasher);
-        hasher.finish()
+        hash_str(&self)
     }
 }
 
 impl Seed for &String {
     fn to_value(self) -> u64 {
-        let mut hasher = DefaultHasher::new();
-        self.hash(&mut hasher);
-        hasher.finish()
+        hash_str(self)
     }
 }
 
 impl Seed for &str {
     fn to_value(self) -> u64 {
-        let mut hasher = DefaultHasher::new();
-        self.hash(&mut hasher);
-        hasher.finish()
+        hash_str(self)
     }
-}
\ No newline at end of file
+}
+
+/// Hashes a string with the standard library's `DefaultHasher`.
+///
+/// Parameters:
+/// * `value`: The string to hash.
+#[cfg(not(feature = "fast_hash"))]
+pub(crate) fn hash_str(value: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes a string with the 64-bit FNV-1a algorithm.
+///
+/// Parameters:
+/// * `value`: The string to hash.
+#[cfg(feature = "fast_hash")]
+pub(crate) fn hash_str(value: &str) -> u64 {
+    value.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
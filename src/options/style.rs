@@ -0,0 +1,42 @@
+/// An aesthetic style of the color, picking its saturation and brightness
+/// from a fixed window regardless of the luminosity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// Soft, washed-out colors: low saturation and high brightness.
+    Pastel,
+    /// Glowing colors at or near full saturation and full brightness.
+    Neon,
+    /// Subdued colors: low saturation and medium brightness.
+    Muted,
+    /// Strong, saturated colors that are still easy on the eyes.
+    Vivid,
+}
+
+impl Style {
+    /// Returns the range saturation values are picked from, clamped to the
+    /// saturation range of the hue. The upper bound is exclusive.
+    ///
+    /// Parameters:
+    /// * `s_min`: The minimum saturation of the hue.
+    /// * `s_max`: The maximum saturation of the hue.
+    pub(crate) fn saturation_window(&self, s_min: i64, s_max: i64) -> (i64, i64) {
+        let (min, max) = match self {
+            Style::Pastel | Style::Muted => (15, 40),
+            Style::Neon => (90, 101),
+            Style::Vivid => (70, 90),
+        };
+
+        (min.clamp(s_min, s_max), max.clamp(s_min, s_max + 1))
+    }
+
+    /// Returns the range brightness values are picked from, which does not
+    /// depend on the hue. The upper bound is exclusive.
+    pub(crate) fn brightness_window(&self) -> (i64, i64) {
+        match self {
+            Style::Pastel => (88, 101),
+            Style::Neon => (95, 101),
+            Style::Muted => (45, 70),
+            Style::Vivid => (75, 95),
+        }
+    }
+}
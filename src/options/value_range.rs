@@ -0,0 +1,52 @@
+use std::ops::RangeInclusive;
+
+/// An inclusive range of saturation or brightness values, in the range
+/// [0, 100]. A single value is a range where both ends are equal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValueRange {
+    /// The lowest value of the range.
+    pub min: i64,
+    /// The highest value of the range.
+    pub max: i64,
+}
+
+impl ValueRange {
+    /// Creates a new `ValueRange` instance, clamping both ends to [0, 100]
+    /// and swapping them if needed.
+    ///
+    /// Parameters:
+    /// * `min`: The lowest value of the range.
+    /// * `max`: The highest value of the range.
+    pub fn new(min: i64, max: i64) -> ValueRange {
+        let (min, max) = (min.clamp(0, 100), max.clamp(0, 100));
+
+        ValueRange {
+            min: min.min(max),
+            max: min.max(max),
+        }
+    }
+}
+
+impl From<i64> for ValueRange {
+    fn from(value: i64) -> Self {
+        ValueRange::new(value, value)
+    }
+}
+
+impl From<i32> for ValueRange {
+    fn from(value: i32) -> Self {
+        ValueRange::from(value as i64)
+    }
+}
+
+impl From<RangeInclusive<i64>> for ValueRange {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        ValueRange::new(*range.start(), *range.end())
+    }
+}
+
+impl From<RangeInclusive<i32>> for ValueRange {
+    fn from(range: RangeInclusive<i32>) -> Self {
+        ValueRange::new(*range.start() as i64, *range.end() as i64)
+    }
+}
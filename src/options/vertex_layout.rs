@@ -0,0 +1,9 @@
+/// The memory layout of generated vertex colors.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexLayout {
+    /// The channels of each vertex are stored together: `RGBARGBA...`.
+    #[default]
+    Interleaved,
+    /// Each channel is stored in its own contiguous plane: `RR...GG...BB...AA...`.
+    Planar,
+}
@@ -0,0 +1,12 @@
+/// The strategy used to separate a white component from an RGB color.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhiteExtraction {
+    /// The white channel takes the smallest RGB channel, which is then
+    /// subtracted from all three channels.
+    #[default]
+    MinChannel,
+    /// The white channel takes the Rec. 709 luma of the color, weighted by
+    /// how desaturated the color is, and is then subtracted from all three
+    /// channels.
+    Luminance,
+}
@@ -0,0 +1,20 @@
+/// The standard whose luma coefficients are used to convert a color to
+/// YCbCr.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YCbCrStandard {
+    /// ITU-R BT.709, used for HD video.
+    #[default]
+    Bt709,
+    /// ITU-R BT.601, used for SD video.
+    Bt601,
+}
+
+impl YCbCrStandard {
+    /// Returns the red and blue luma coefficients of the standard.
+    pub fn coefficients(&self) -> (f32, f32) {
+        match self {
+            YCbCrStandard::Bt709 => (0.2126, 0.0722),
+            YCbCrStandard::Bt601 => (0.299, 0.114),
+        }
+    }
+}
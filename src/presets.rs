@@ -0,0 +1,82 @@
+use crate::RandomColor;
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+/// A process-wide registry of named generator configurations, so large
+/// codebases can define them in one place and reference them by name.
+///
+/// ```rust
+/// use random_color::presets::Presets;
+/// use random_color::options::{Gamut, Luminosity};
+/// use random_color::RandomColor;
+///
+/// Presets::register(
+///     "brand-accents",
+///     RandomColor::new().hue(Gamut::Blue).luminosity(Luminosity::Bright).clone(),
+/// );
+///
+/// let mut accents = RandomColor::from_preset("brand-accents").unwrap();
+/// println!("{}", accents.to_hex());
+/// ```
+pub struct Presets;
+
+impl Presets {
+    /// Registers a generator configuration under the given name, replacing
+    /// any previous one with the same name.
+    ///
+    /// Parameters:
+    /// * `name`: The name of the preset.
+    /// * `random_color`: The generator configuration.
+    pub fn register(name: &str, random_color: RandomColor) {
+        Presets::registry()
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(name.to_string(), random_color);
+    }
+
+    /// Removes the preset with the given name, returning it if it existed.
+    ///
+    /// Parameters:
+    /// * `name`: The name of the preset.
+    pub fn unregister(name: &str) -> Option<RandomColor> {
+        Presets::registry()
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(name)
+    }
+
+    /// Returns a copy of the preset with the given name, if any.
+    ///
+    /// The copy includes the state of the preset's random number generator,
+    /// so a seeded preset generates the same colors every time it is fetched.
+    ///
+    /// Parameters:
+    /// * `name`: The name of the preset.
+    pub fn get(name: &str) -> Option<RandomColor> {
+        Presets::registry()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(name)
+            .cloned()
+    }
+
+    /// Returns the names of the registered presets, sorted alphabetically.
+    pub fn names() -> Vec<String> {
+        let mut names: Vec<String> = Presets::registry()
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .cloned()
+            .collect();
+        names.sort();
+
+        names
+    }
+
+    /// Returns the global registry.
+    fn registry() -> &'static RwLock<HashMap<String, RandomColor>> {
+        static REGISTRY: OnceLock<RwLock<HashMap<String, RandomColor>>> = OnceLock::new();
+
+        REGISTRY.get_or_init(|| RwLock::new(HashMap::new()))
+    }
+}
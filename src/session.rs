@@ -0,0 +1,115 @@
+use crate::color::Color;
+use crate::RandomColor;
+use rand::rngs::SmallRng;
+
+/// An interactive generation session, recording each generated color along
+/// with the random state it was drawn from, so that colors can be undone,
+/// redone and replayed.
+///
+/// Undoing a color rewinds the generator to the state before it, so
+/// generating again yields the same color unless the generator's settings
+/// changed in between. Generating a new color discards the undone colors.
+#[derive(Debug, Clone)]
+pub struct Session {
+    /// The generator used to pick new colors.
+    pub generator: RandomColor,
+    entries: Vec<SessionEntry>,
+    cursor: usize,
+}
+
+#[derive(Debug, Clone)]
+struct SessionEntry {
+    state: SmallRng,
+    color: Color,
+}
+
+impl Session {
+    /// Creates a new `Session` instance.
+    ///
+    /// Parameters:
+    /// * `generator`: The generator used to pick new colors.
+    pub fn new(generator: RandomColor) -> Session {
+        Session {
+            generator,
+            entries: Vec::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Generates a new color and records it, discarding any undone colors.
+    pub fn next_color(&mut self) -> Color {
+        self.entries.truncate(self.cursor);
+
+        let state = self.generator.seed.clone();
+        let color = self.generator.to_color();
+
+        self.entries.push(SessionEntry { state, color });
+        self.cursor += 1;
+
+        color
+    }
+
+    /// Undoes the last color, rewinding the generator to the random state
+    /// before it. Returns the undone color, or `None` if there is nothing to
+    /// undo.
+    pub fn undo(&mut self) -> Option<Color> {
+        if self.cursor == 0 {
+            return None;
+        }
+
+        self.cursor -= 1;
+        let entry = &self.entries[self.cursor];
+        self.generator.seed = entry.state.clone();
+
+        Some(entry.color)
+    }
+
+    /// Redoes the last undone color, generating it again from its recorded
+    /// random state. Returns the redone color, or `None` if there is nothing
+    /// to redo.
+    pub fn redo(&mut self) -> Option<Color> {
+        let entry = self.entries.get_mut(self.cursor)?;
+
+        self.generator.seed = entry.state.clone();
+        entry.color = self.generator.to_color();
+        self.cursor += 1;
+
+        Some(entry.color)
+    }
+
+    /// Generates every color of the history again from its recorded random
+    /// state, e.g. after changing the generator's settings, so each color
+    /// keeps its own random draws. Returns the updated history.
+    pub fn replay(&mut self) -> Vec<Color> {
+        for entry in self.entries[..self.cursor].iter_mut() {
+            self.generator.seed = entry.state.clone();
+            entry.color = self.generator.to_color();
+        }
+
+        self.history()
+    }
+
+    /// Returns the colors generated so far, oldest first, without the undone
+    /// ones.
+    pub fn history(&self) -> Vec<Color> {
+        self.entries[..self.cursor]
+            .iter()
+            .map(|entry| entry.color)
+            .collect()
+    }
+
+    /// Returns the last generated color that was not undone.
+    pub fn current(&self) -> Option<Color> {
+        self.cursor.checked_sub(1).map(|i| self.entries[i].color)
+    }
+
+    /// Checks whether there is a color to undo.
+    pub fn can_undo(&self) -> bool {
+        self.cursor > 0
+    }
+
+    /// Checks whether there is a color to redo.
+    pub fn can_redo(&self) -> bool {
+        self.cursor < self.entries.len()
+    }
+}
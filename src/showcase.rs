@@ -0,0 +1,56 @@
+use crate::color::Color;
+use crate::options::Seed;
+use crate::RandomColor;
+
+/// Generates a seeded palette and returns it as CSS custom properties, e.g.
+/// `--brand-1: #aeecf9;`, declared on `:root`.
+///
+/// Parameters:
+/// * `seed`: The seed of the palette.
+/// * `count`: The number of colors.
+/// * `prefix`: The prefix of the custom properties.
+pub fn palette_css_variables<T: Seed>(seed: T, count: usize, prefix: &str) -> String {
+    let palette = RandomColor::new().seed(seed).to_palette(count);
+    let mut css = String::from(":root {\n");
+
+    for (i, color) in palette.iter().enumerate() {
+        css.push_str(&format!("  --{}-{}: {};\n", prefix, i + 1, color.to_hex()));
+    }
+
+    css.push_str("}\n");
+
+    css
+}
+
+/// Returns the background and text colors of an avatar for the given
+/// identifier, e.g. a user ID or name.
+///
+/// The background is stable for each identifier, and the text is black or
+/// white, whichever contrasts the most with it.
+///
+/// Parameters:
+/// * `id`: The identifier of the avatar.
+pub fn avatar_colors<T: Seed>(id: T) -> (Color, Color) {
+    let background = RandomColor::new().seed(id).to_color();
+
+    (background, background.readable_text_color())
+}
+
+/// Generates a seeded palette and returns it as a terminal preview: a row of
+/// color blocks spanning `width` columns, followed by one labeled swatch per
+/// color.
+///
+/// Parameters:
+/// * `seed`: The seed of the palette.
+/// * `count`: The number of colors.
+/// * `width`: The width of the terminal, in columns.
+#[cfg(feature = "ansi_preview")]
+pub fn terminal_palette<T: Seed>(seed: T, count: usize, width: usize) -> String {
+    let palette = RandomColor::new().seed(seed).to_palette(count);
+
+    format!(
+        "{}{}",
+        palette.to_block_rows(width),
+        palette.preview_string()
+    )
+}
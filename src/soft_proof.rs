@@ -0,0 +1,136 @@
+//! Soft-proofing: previewing how colors appear on displays with a limited
+//! gamut or a different white point.
+
+use crate::color::Color;
+use crate::conversions;
+use crate::options::GamutMapping;
+
+/// The tolerance on the display channels below which a color is still
+/// considered inside the gamut of a display.
+const GAMUT_TOLERANCE: f32 = 1e-4;
+
+/// The gamut and white point of a target display, as xy chromaticities.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplayProfile {
+    /// The chromaticities of the red, green and blue primaries.
+    pub primaries: [[f32; 2]; 3],
+    /// The chromaticity of the white point.
+    pub white_point: [f32; 2],
+}
+
+impl DisplayProfile {
+    /// Creates a new `DisplayProfile` instance.
+    ///
+    /// Parameters:
+    /// * `primaries`: The xy chromaticities of the red, green and blue
+    ///   primaries.
+    /// * `white_point`: The xy chromaticity of the white point.
+    pub fn new(primaries: [[f32; 2]; 3], white_point: [f32; 2]) -> DisplayProfile {
+        DisplayProfile {
+            primaries,
+            white_point,
+        }
+    }
+
+    /// Returns the profile of a standard sRGB display, on which colors appear
+    /// unchanged.
+    pub fn srgb() -> DisplayProfile {
+        DisplayProfile::new([[0.64, 0.33], [0.3, 0.6], [0.15, 0.06]], [0.3127, 0.329])
+    }
+
+    /// Returns how the color appears on the display, as an sRGB color.
+    ///
+    /// The color is rendered relative to the white point of the display, so
+    /// white shows as the display's white, and the channels that exceed the
+    /// display's gamut are clipped. Alpha is kept as is.
+    ///
+    /// Parameters:
+    /// * `color`: The color to preview.
+    pub fn proof(&self, color: Color) -> Color {
+        self.proof_mapped(color, GamutMapping::default())
+    }
+
+    /// Returns how the color appears on the display like `proof`, bringing
+    /// the colors of displays wider than sRGB back into the sRGB gamut with
+    /// the given gamut mapping instead of clipping them.
+    ///
+    /// Parameters:
+    /// * `color`: The color to preview.
+    /// * `mapping`: The gamut mapping.
+    pub fn proof_mapped(&self, color: Color, mapping: GamutMapping) -> Color {
+        let display = self.display_channels(color).map(|c| c.clamp(0.0, 1.0));
+        let xyz = conversions::transform(&self.xyz_matrix(), display);
+
+        let [r, g, b] = mapping.map(conversions::xyz_to_rgb(xyz));
+
+        Color::new(r, g, b, color.a)
+    }
+
+    /// Checks whether the color can be shown on the display without
+    /// clipping.
+    ///
+    /// Parameters:
+    /// * `color`: The color to check.
+    pub fn in_gamut(&self, color: Color) -> bool {
+        self.display_channels(color)
+            .iter()
+            .all(|c| (-GAMUT_TOLERANCE..=1.0 + GAMUT_TOLERANCE).contains(c))
+    }
+
+    /// Converts a color to the linear channels of the display, relative to
+    /// its white point.
+    fn display_channels(&self, color: Color) -> [f32; 3] {
+        let xyz = conversions::rgb_to_xyz([color.r, color.g, color.b].map(|c| c as f32 / 255.0));
+        let adapted = conversions::adapt_white_point(
+            xyz,
+            conversions::D65_WHITE,
+            chromaticity_to_xyz(self.white_point),
+        );
+
+        conversions::transform(&invert(&self.xyz_matrix()), adapted)
+    }
+
+    /// Returns the matrix converting the linear channels of the display to
+    /// CIE XYZ.
+    fn xyz_matrix(&self) -> [[f32; 3]; 3] {
+        let [r, g, b] = self.primaries.map(chromaticity_to_xyz);
+        let primaries = [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]];
+        let [sr, sg, sb] =
+            conversions::transform(&invert(&primaries), chromaticity_to_xyz(self.white_point));
+
+        primaries.map(|[x, y, z]| [x * sr, y * sg, z * sb])
+    }
+}
+
+/// Converts an xy chromaticity to CIE XYZ, with a Y of 1.0.
+fn chromaticity_to_xyz([x, y]: [f32; 2]) -> [f32; 3] {
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+/// Inverts a 3×3 matrix.
+fn invert(m: &[[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    let cofactor =
+        |r1: usize, r2: usize, c1: usize, c2: usize| m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1];
+
+    let determinant = m[0][0] * cofactor(1, 2, 1, 2) - m[0][1] * cofactor(1, 2, 0, 2)
+        + m[0][2] * cofactor(1, 2, 0, 1);
+
+    [
+        [
+            cofactor(1, 2, 1, 2),
+            -cofactor(0, 2, 1, 2),
+            cofactor(0, 1, 1, 2),
+        ],
+        [
+            -cofactor(1, 2, 0, 2),
+            cofactor(0, 2, 0, 2),
+            -cofactor(0, 1, 0, 2),
+        ],
+        [
+            cofactor(1, 2, 0, 1),
+            -cofactor(0, 2, 0, 1),
+            cofactor(0, 1, 0, 1),
+        ],
+    ]
+    .map(|row| row.map(|c| c / determinant))
+}
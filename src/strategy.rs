@@ -0,0 +1,73 @@
+use crate::RandomColor;
+use std::fmt;
+use std::sync::Arc;
+
+/// A strategy for picking the hue, saturation and brightness of generated
+/// colors.
+///
+/// Every method defaults to the crate's own heuristics, so a strategy only
+/// needs to override the steps it wants to change. The generator is passed
+/// in so strategies can read its settings and draw from its seeded RNG
+/// (`random_color.seed`).
+pub trait PickStrategy: Send + Sync {
+    /// Picks the hue of a color, in the range [0, 360).
+    ///
+    /// Parameters:
+    /// * `random_color`: The generator the color is generated by.
+    fn pick_hue(&self, random_color: &mut RandomColor) -> i64 {
+        random_color.pick_hue()
+    }
+
+    /// Picks the saturation of a color, in the range [0, 100].
+    ///
+    /// Parameters:
+    /// * `random_color`: The generator the color is generated by.
+    /// * `hue`: The hue of the color.
+    fn pick_saturation(&self, random_color: &mut RandomColor, hue: i64) -> i64 {
+        random_color.pick_saturation(&hue)
+    }
+
+    /// Picks the brightness of a color, in the range [0, 100].
+    ///
+    /// Parameters:
+    /// * `random_color`: The generator the color is generated by.
+    /// * `hue`: The hue of the color.
+    /// * `saturation`: The saturation of the color.
+    fn pick_brightness(&self, random_color: &mut RandomColor, hue: i64, saturation: i64) -> i64 {
+        random_color.pick_brightness(&hue, &saturation)
+    }
+}
+
+/// The crate's default picking heuristics, inspired by randomColor.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultPickStrategy;
+
+impl PickStrategy for DefaultPickStrategy {}
+
+/// A shareable handle to a `PickStrategy`, as stored by `RandomColor`.
+#[derive(Clone)]
+pub struct SharedPickStrategy(Arc<dyn PickStrategy>);
+
+impl SharedPickStrategy {
+    /// Creates a new `SharedPickStrategy` instance.
+    pub fn new<S: PickStrategy + 'static>(strategy: S) -> Self {
+        SharedPickStrategy(Arc::new(strategy))
+    }
+
+    /// Returns the wrapped strategy.
+    pub fn get(&self) -> &dyn PickStrategy {
+        self.0.as_ref()
+    }
+}
+
+impl fmt::Debug for SharedPickStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedPickStrategy(..)")
+    }
+}
+
+impl PartialEq for SharedPickStrategy {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
@@ -0,0 +1,107 @@
+//! Deterministic test vectors, for downstream projects to snapshot and
+//! detect when an upgrade of this crate changes their generated colors.
+//!
+//! ```rust
+//! use random_color::test_vectors::test_vectors;
+//!
+//! let snapshot: String = test_vectors(42, 45)
+//!     .iter()
+//!     .map(|vector| format!("{}\n", vector))
+//!     .collect();
+//! # let _ = snapshot;
+//! ```
+
+use crate::options::{Gamut, Luminosity, Seed};
+use crate::RandomColor;
+use std::fmt;
+
+/// The hue settings the test vectors cycle through.
+const HUES: [Option<Gamut>; 9] = [
+    None,
+    Some(Gamut::Monochrome),
+    Some(Gamut::Red),
+    Some(Gamut::Orange),
+    Some(Gamut::Yellow),
+    Some(Gamut::Green),
+    Some(Gamut::Blue),
+    Some(Gamut::Purple),
+    Some(Gamut::Pink),
+];
+
+/// The luminosity settings the test vectors cycle through.
+const LUMINOSITIES: [Option<Luminosity>; 5] = [
+    None,
+    Some(Luminosity::Random),
+    Some(Luminosity::Bright),
+    Some(Luminosity::Light),
+    Some(Luminosity::Dark),
+];
+
+/// A generated color together with the seed and options that produced it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestVector {
+    /// The seed of the generator.
+    pub seed: u64,
+    /// The hue setting of the generator.
+    pub hue: Option<Gamut>,
+    /// The luminosity setting of the generator.
+    pub luminosity: Option<Luminosity>,
+    /// The generated color, as an HSV array, with the hue in the range
+    /// [0, 360).
+    pub hsv: [u32; 3],
+    /// The generated color, as an RGB array.
+    pub rgb: [u8; 3],
+    /// The generated color, as a hex string.
+    pub hex: String,
+}
+
+impl fmt::Display for TestVector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [h, s, v] = self.hsv;
+        let [r, g, b] = self.rgb;
+
+        write!(
+            f,
+            "seed={} hue={:?} luminosity={:?} hsv={},{},{} rgb={},{},{} hex={}",
+            self.seed, self.hue, self.luminosity, h, s, v, r, g, b, self.hex
+        )
+    }
+}
+
+/// Generates a canonical list of test vectors.
+///
+/// The `i`-th vector is generated from the seed `seed + i` and cycles
+/// through every combination of the hue and luminosity settings, so the
+/// same arguments always yield the same list.
+///
+/// Parameters:
+/// * `seed`: The seed of the first vector.
+/// * `count`: The number of vectors.
+pub fn test_vectors<T: Seed>(seed: T, count: usize) -> Vec<TestVector> {
+    let base = seed.to_value();
+
+    (0..count)
+        .map(|i| {
+            let seed = base.wrapping_add(i as u64);
+            let hue = HUES[i % HUES.len()];
+            let luminosity = LUMINOSITIES[i / HUES.len() % LUMINOSITIES.len()];
+
+            let mut random_color = RandomColor::new();
+            random_color.seed(seed);
+            random_color.hue = hue;
+            random_color.luminosity = luminosity;
+
+            let (h, s, v) = random_color.clone().generate_color();
+            let rgb = random_color.clone().to_rgb_array();
+
+            TestVector {
+                seed,
+                hue,
+                luminosity,
+                hsv: [h.rem_euclid(360) as u32, s as u32, v as u32],
+                rgb,
+                hex: random_color.to_hex(),
+            }
+        })
+        .collect()
+}
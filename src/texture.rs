@@ -0,0 +1,119 @@
+//! Debug texture generators filling RGBA buffers with random colors.
+//!
+//! Every function takes a tightly packed RGBA buffer of `width` pixels per
+//! row; the height is derived from the buffer length.
+
+use crate::RandomColor;
+use rand::Rng;
+
+/// The direction of the stripes drawn by `stripes`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StripeDirection {
+    #[default]
+    Horizontal,
+    Vertical,
+}
+
+/// Fills the buffer with a checkerboard of two random colors.
+///
+/// Parameters:
+/// * `random_color`: The generator used to pick the colors.
+/// * `buffer`: The RGBA buffer to fill.
+/// * `width`: The width in pixels of the image.
+/// * `cell_size`: The width and height in pixels of each square.
+pub fn checkerboard(
+    random_color: &mut RandomColor,
+    buffer: &mut [u8],
+    width: usize,
+    cell_size: usize,
+) {
+    let colors = [random_color.to_rgba_array(), random_color.to_rgba_array()];
+    let cell_size = cell_size.max(1);
+
+    fill(buffer, width, |x, y| {
+        colors[(x / cell_size + y / cell_size) % 2]
+    });
+}
+
+/// Fills the buffer with stripes, each one with a new random color.
+///
+/// Parameters:
+/// * `random_color`: The generator used to pick the colors.
+/// * `buffer`: The RGBA buffer to fill.
+/// * `width`: The width in pixels of the image.
+/// * `stripe_size`: The thickness in pixels of each stripe.
+/// * `direction`: The direction of the stripes.
+pub fn stripes(
+    random_color: &mut RandomColor,
+    buffer: &mut [u8],
+    width: usize,
+    stripe_size: usize,
+    direction: StripeDirection,
+) {
+    let stripe_size = stripe_size.max(1);
+    let length = match direction {
+        StripeDirection::Horizontal => height(buffer, width),
+        StripeDirection::Vertical => width,
+    };
+    let colors: Vec<[u8; 4]> = (0..length.div_ceil(stripe_size))
+        .map(|_| random_color.to_rgba_array())
+        .collect();
+
+    fill(buffer, width, |x, y| match direction {
+        StripeDirection::Horizontal => colors[y / stripe_size],
+        StripeDirection::Vertical => colors[x / stripe_size],
+    });
+}
+
+/// Fills the buffer with Voronoi cells around random sites, each cell with
+/// its own random color.
+///
+/// Parameters:
+/// * `random_color`: The generator used to pick the sites and colors.
+/// * `buffer`: The RGBA buffer to fill.
+/// * `width`: The width in pixels of the image.
+/// * `cells`: The number of cells.
+pub fn voronoi(random_color: &mut RandomColor, buffer: &mut [u8], width: usize, cells: usize) {
+    let height = height(buffer, width);
+
+    if width == 0 || height == 0 || cells == 0 {
+        return;
+    }
+
+    let sites: Vec<(usize, usize, [u8; 4])> = (0..cells)
+        .map(|_| {
+            let x = random_color.seed.gen_range(0..width);
+            let y = random_color.seed.gen_range(0..height);
+
+            (x, y, random_color.to_rgba_array())
+        })
+        .collect();
+
+    fill(buffer, width, |x, y| {
+        sites
+            .iter()
+            .min_by_key(|(sx, sy, _)| sx.abs_diff(x).pow(2) + sy.abs_diff(y).pow(2))
+            .map(|(_, _, color)| *color)
+            .unwrap()
+    });
+}
+
+/// Returns the height in pixels of an RGBA buffer with the given width,
+/// counting a partial last row.
+fn height(buffer: &[u8], width: usize) -> usize {
+    match width {
+        0 => 0,
+        _ => (buffer.len() / 4).div_ceil(width),
+    }
+}
+
+/// Sets every whole pixel of the buffer to the color returned by `color_at`.
+fn fill<F: Fn(usize, usize) -> [u8; 4]>(buffer: &mut [u8], width: usize, color_at: F) {
+    if width == 0 {
+        return;
+    }
+
+    for (i, pixel) in buffer.chunks_exact_mut(4).enumerate() {
+        pixel.copy_from_slice(&color_at(i % width, i / width));
+    }
+}
@@ -0,0 +1,80 @@
+//! Helpers for deriving the colors of UI components from generated colors.
+
+use crate::color::Color;
+
+/// Variants of the same hue for each luminosity, e.g. for the hover, active
+/// and disabled states of a UI component, as returned by
+/// `RandomColor::generate_luminosity_set`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LuminositySet {
+    /// The variant with `Luminosity::Light`.
+    pub light: Color,
+    /// The variant without a luminosity setting.
+    pub default: Color,
+    /// The variant with `Luminosity::Bright`.
+    pub bright: Color,
+    /// The variant with `Luminosity::Dark`.
+    pub dark: Color,
+}
+
+/// The colors of a UI component in each of its states, derived from a single
+/// generated color, as returned by `RandomColor::to_ui_state_colors`.
+///
+/// Each text color is black or white, whichever contrasts the most with the
+/// fill it is drawn on, which guarantees a WCAG contrast ratio of at least
+/// 4.5. Disabled components are exempt from contrast requirements, so their
+/// text is not derived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UiStateColors {
+    /// The fill of the component at rest.
+    pub default: Color,
+    /// The fill of the component when hovered.
+    pub hover: Color,
+    /// The fill of the component when pressed.
+    pub active: Color,
+    /// The fill of the component when disabled.
+    pub disabled: Color,
+    /// The border of the component.
+    pub border: Color,
+    /// The text on the component at rest.
+    pub text: Color,
+    /// The text on the component when hovered.
+    pub hover_text: Color,
+    /// The text on the component when pressed.
+    pub active_text: Color,
+}
+
+impl UiStateColors {
+    /// Derives the state colors from the variants of a luminosity set.
+    ///
+    /// The bright variant is used when hovered, the dark one when pressed
+    /// and for the border, and the light one when disabled.
+    ///
+    /// Parameters:
+    /// * `set`: The luminosity set to derive the colors from.
+    pub fn from_luminosity_set(set: &LuminositySet) -> UiStateColors {
+        UiStateColors {
+            default: set.default,
+            hover: set.bright,
+            active: set.dark,
+            disabled: set.light,
+            border: set.dark,
+            text: set.default.readable_text_color(),
+            hover_text: set.bright.readable_text_color(),
+            active_text: set.dark.readable_text_color(),
+        }
+    }
+
+    /// Returns the lowest WCAG contrast ratio between the text and its fill,
+    /// across the default, hover and active states.
+    pub fn text_contrast(&self) -> f32 {
+        [
+            (self.text, self.default),
+            (self.hover_text, self.hover),
+            (self.active_text, self.active),
+        ]
+        .iter()
+        .map(|(text, fill)| text.contrast_ratio(fill))
+        .fold(f32::INFINITY, f32::min)
+    }
+}
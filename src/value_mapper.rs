@@ -0,0 +1,80 @@
+use crate::color::Color;
+use crate::conversions;
+use crate::options::GamutMapping;
+
+/// Maps numeric values to colors by interpolating between anchor colors in
+/// OKLab, e.g. for heat maps and choropleths.
+///
+/// Anchors are usually supplied, or generated with
+/// `RandomColor::to_value_mapper` for a seeded yet controlled mapping.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueMapper {
+    anchors: Vec<(f32, [f32; 3], f32)>,
+    gamut_mapping: GamutMapping,
+}
+
+impl ValueMapper {
+    /// Creates a new `ValueMapper` instance.
+    ///
+    /// The anchors do not need to be sorted. Values below the first anchor or
+    /// above the last one map to the color of that anchor.
+    ///
+    /// Parameters:
+    /// * `anchors`: The values and their colors.
+    pub fn new(anchors: &[(f32, Color)]) -> ValueMapper {
+        let mut anchors: Vec<(f32, [f32; 3], f32)> = anchors
+            .iter()
+            .map(|(value, color)| {
+                let rgb = [color.r, color.g, color.b].map(|c| c as f32 / 255.0);
+
+                (*value, conversions::rgb_to_oklab(rgb), color.a as f32)
+            })
+            .collect();
+        anchors.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        ValueMapper {
+            anchors,
+            gamut_mapping: GamutMapping::default(),
+        }
+    }
+
+    /// Sets how interpolated colors outside the sRGB gamut are brought into
+    /// it. Defaults to clipping.
+    ///
+    /// Parameters:
+    /// * `mapping`: The gamut mapping.
+    pub fn gamut_mapping(&mut self, mapping: GamutMapping) -> &mut ValueMapper {
+        self.gamut_mapping = mapping;
+
+        self
+    }
+
+    /// Returns the color of the given value, or `None` if there are no
+    /// anchors.
+    ///
+    /// Parameters:
+    /// * `value`: The value to map.
+    pub fn map(&self, value: f32) -> Option<Color> {
+        let first = self.anchors.first()?;
+        let last = self.anchors.last()?;
+
+        let (lab, alpha) = if value <= first.0 {
+            (first.1, first.2)
+        } else if value >= last.0 {
+            (last.1, last.2)
+        } else {
+            let index = self.anchors.iter().rposition(|a| a.0 <= value).unwrap();
+            let (from, to) = (self.anchors[index], self.anchors[index + 1]);
+            let t = (value - from.0) / (to.0 - from.0);
+
+            (
+                [0, 1, 2].map(|c| from.1[c] + (to.1[c] - from.1[c]) * t),
+                from.2 + (to.2 - from.2) * t,
+            )
+        };
+
+        let [r, g, b] = self.gamut_mapping.map(conversions::oklab_to_rgb(lab));
+
+        Some(Color::new(r, g, b, alpha.round() as u8))
+    }
+}